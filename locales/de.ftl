@@ -0,0 +1,151 @@
+# Deutsche Übersetzung
+
+language-name = Deutsch
+language-system = Systemsprache verwenden
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 Nutzung
+menu-language = ----- 🇺🇳 Sprache -----
+menu-refresh-interval = ----- ⏳ Aktualisierungsintervall -----
+menu-icon = ----- 🎨 Symbol -----
+menu-options = ----- ⚙️ Optionen -----
+
+## 用量信息
+
+requests = { $count ->
+    [one] { $count } Anfrage
+   *[other] { $count } Anfragen
+}
+usage-used = Verbraucht: { $used }/{ $total } Anfragen
+usage-remaining = Verbleibend: { requests }
+usage-rate = Auslastung: { $percentage } %
+usage-rate-level = Auslastung: { $percentage } % ({ $level })
+usage-pace-ahead = Tempo: { $percentage } % voraus
+usage-pace-behind = Tempo: { $percentage } % zurück
+usage-account = Konto: { $email }
+usage-last-update = Zuletzt aktualisiert: { $time }
+usage-never = nie
+usage-stale = ⚠️ Daten sind veraltet, letzte erfolgreiche Aktualisierung { $time }
+usage-refresh-failed = ❌ Letzte Aktualisierung fehlgeschlagen: { $error }
+usage-error = ❌ Fehler: { $error }
+
+## 用量预测
+
+forecast-rate = ~{ $rate } Anfragen/Tag
+forecast-current-pace = 📈 Aktuelles Tempo: { $rate }
+forecast-runs-out = 📈 Beim aktuellen Tempo aufgebraucht am { $date } ({ $rate })
+forecast-runs-out-before-reset = 📈 Beim aktuellen Tempo aufgebraucht am { $date } ({ $days ->
+    [one] { $days } Tag
+   *[other] { $days } Tage
+} vor dem Zurücksetzen)
+forecast-lasts-until-reset = 📈 Beim aktuellen Tempo reicht das Kontingent bis zum Zurücksetzen ({ $rate })
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %d.%m.%Y, %H:%M
+date-month-day = %-d. %b
+date-short = %d.%m.
+date-day = { $weekday } { $date }
+relative-just-now = gerade eben
+relative-minutes = vor { $count } Min.
+relative-hours = vor { $count } Std.
+relative-days = { $count ->
+    [one] vor { $count } Tag
+   *[other] vor { $count } Tagen
+}
+duration-hours = { $count ->
+    [one] { $count } Stunde
+   *[other] { $count } Stunden
+}
+duration-minutes = { $count ->
+    [one] { $count } Minute
+   *[other] { $count } Minuten
+}
+duration-seconds = { $count ->
+    [one] { $count } Sekunde
+   *[other] { $count } Sekunden
+}
+duration-separator = { " " }
+list-separator = { ", " }
+weekday-mon = Mo
+weekday-tue = Di
+weekday-wed = Mi
+weekday-thu = Do
+weekday-fri = Fr
+weekday-sat = Sa
+weekday-sun = So
+
+## 每日用量
+
+breakdown = 📊 Tägliche Nutzung
+breakdown-today = Heute: { requests }
+breakdown-yesterday = Gestern: { requests }
+breakdown-this-week = Diese Woche: { requests }
+
+## 刷新间隔
+
+interval-adaptive = Adaptiv
+interval-custom = Benutzerdefiniert: { $duration }
+quiet-hours = 🌙 Ruhezeiten: { $when } · { $action }
+quiet-paused = pausiert
+quiet-slow = alle { $duration }
+quiet-active = { " " }(jetzt aktiv)
+
+## 图标
+
+color-absolute = Nach Auslastung
+color-pace = Nach Tempo
+skip-weekends = Wochenenden beim Tempo überspringen
+style-disc = Kreissymbol
+style-sparkline = Verlaufssymbol
+style-bars = Tagesbalken-Symbol
+levels-standard = Stufen: Standard (50/70/90 %)
+levels-relaxed = Stufen: großzügig (70/85/95 %)
+levels-strict = Stufen: streng (30/50/75 %)
+levels-color-blind = Stufen: farbenblind-freundlich
+levels-custom = Stufen: benutzerdefiniert
+level-low = Niedrig
+level-moderate = Mittel
+level-high = Hoch
+level-critical = Kritisch
+
+## 选项
+
+refresh = Daten aktualisieren
+pause = ⏸ Überwachung pausieren
+pause-1h = Für 1 Stunde
+pause-tomorrow = Bis morgen
+pause-indefinitely = Bis zur Fortsetzung
+resume = ▶️ Überwachung fortsetzen
+paused-until = ⏸ Überwachung pausiert bis { $time }
+paused = ⏸ Überwachung pausiert
+export = 📤 Verlauf exportieren
+export-this-month = Dieser Monat
+export-last-month = Letzter Monat
+export-last-30-days = Letzte 30 Tage
+export-item = { $range } ({ $format })
+export-format-text = Text
+export-column-time = Zeit
+export-column-account = Konto
+export-column-model = Modell
+export-column-used = Verbraucht
+export-column-limit = Limit
+export-column-delta = Änderung
+export-column-spend = Ausgaben
+settings = Cursor-Einstellungen öffnen
+quit = Beenden
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file } konnte nicht gelesen werden und wurde nach { $path } gesichert
+settings-unreadable = ⚠️ { $file } konnte nicht gelesen werden, Standardeinstellungen werden verwendet: { $error }
+settings-invalid-fields = ⚠️ Ungültige Einstellungen ignoriert: { $fields }
+settings-reload-failed = ⚠️ { $file } enthält Fehler, die aktuellen Einstellungen bleiben aktiv: { $error }
+managed-by-organization = 🔒 Von Ihrer Organisation verwaltet
+policy-invalid-fields = ⚠️ Ungültige Richtlinieneinstellungen ignoriert: { $fields }
+overridden-for-session = ⚙️ Für diesen Start per Befehlszeile oder Umgebungsvariable festgelegt
+error-no-token = Zugriffstoken konnte nicht abgerufen werden. Bitte stelle sicher, dass Cursor installiert ist und du angemeldet bist.
+error-no-user-id = Benutzer-ID konnte nicht aus dem Token gelesen werden
+error-no-usage = Nutzungsdaten konnten nicht abgerufen werden. Bitte überprüfe deine Netzwerkverbindung.
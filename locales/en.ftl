@@ -0,0 +1,152 @@
+# 英文翻译，其他语言缺少的条目会回退到这里
+
+# 语言菜单中显示的语言名称
+language-name = English
+language-system = Follow system
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 Usage
+menu-language = -----🇺🇳 Language -----
+menu-refresh-interval = ----- ⏳ Refresh Interval -----
+menu-icon = ----- 🎨 Icon -----
+menu-options = ----- ⚙️ Options -----
+
+## 用量信息
+
+requests = { $count ->
+    [one] { $count } request
+   *[other] { $count } requests
+}
+usage-used = Used: { $used }/{ $total } requests
+usage-remaining = Remaining: { requests }
+usage-rate = Usage: { $percentage }%
+usage-rate-level = Usage: { $percentage }% ({ $level })
+usage-pace-ahead = Pace: ahead { $percentage }%
+usage-pace-behind = Pace: behind { $percentage }%
+usage-account = Account: { $email }
+usage-last-update = Last updated: { $time }
+usage-never = never
+usage-stale = ⚠️ Data is stale, last successful refresh { $time }
+usage-refresh-failed = ❌ Last refresh failed: { $error }
+usage-error = ❌ Error: { $error }
+
+## 用量预测
+
+forecast-rate = ~{ $rate } requests/day
+forecast-current-pace = 📈 Current pace: { $rate }
+forecast-runs-out = 📈 At current pace: runs out on { $date } ({ $rate })
+forecast-runs-out-before-reset = 📈 At current pace: runs out on { $date } ({ $days ->
+    [one] { $days } day
+   *[other] { $days } days
+} before reset)
+forecast-lasts-until-reset = 📈 At current pace: lasts until reset ({ $rate })
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %b %-d %Y, %H:%M
+date-month-day = %b %-d
+date-short = %m/%d
+date-day = { $weekday } { $date }
+relative-just-now = just now
+relative-minutes = { $count } min ago
+relative-hours = { $count } h ago
+relative-days = { $count ->
+    [one] { $count } day ago
+   *[other] { $count } days ago
+}
+duration-hours = { $count ->
+    [one] { $count } hour
+   *[other] { $count } hours
+}
+duration-minutes = { $count ->
+    [one] { $count } minute
+   *[other] { $count } minutes
+}
+duration-seconds = { $count ->
+    [one] { $count } second
+   *[other] { $count } seconds
+}
+duration-separator = { " " }
+list-separator = { ", " }
+weekday-mon = Mon
+weekday-tue = Tue
+weekday-wed = Wed
+weekday-thu = Thu
+weekday-fri = Fri
+weekday-sat = Sat
+weekday-sun = Sun
+
+## 每日用量
+
+breakdown = 📊 Daily Usage
+breakdown-today = Today: { requests }
+breakdown-yesterday = Yesterday: { requests }
+breakdown-this-week = This week: { requests }
+
+## 刷新间隔
+
+interval-adaptive = Adaptive
+interval-custom = Custom: { $duration }
+quiet-hours = 🌙 Quiet hours: { $when } · { $action }
+quiet-paused = paused
+quiet-slow = every { $duration }
+quiet-active = { " " }(active now)
+
+## 图标
+
+color-absolute = By usage
+color-pace = By pace
+skip-weekends = Skip weekends in pace
+style-disc = Disc icon
+style-sparkline = Sparkline icon
+style-bars = Daily bars icon
+levels-standard = Levels: standard (50/70/90%)
+levels-relaxed = Levels: relaxed (70/85/95%)
+levels-strict = Levels: strict (30/50/75%)
+levels-color-blind = Levels: colour-blind friendly
+levels-custom = Levels: custom
+level-low = Low
+level-moderate = Moderate
+level-high = High
+level-critical = Critical
+
+## 选项
+
+refresh = Refresh Data
+pause = ⏸ Pause Monitoring
+pause-1h = For 1 hour
+pause-tomorrow = Until tomorrow
+pause-indefinitely = Until resumed
+resume = ▶️ Resume Monitoring
+paused-until = ⏸ Monitoring paused until { $time }
+paused = ⏸ Monitoring paused
+export = 📤 Export History
+export-this-month = This month
+export-last-month = Last month
+export-last-30-days = Last 30 days
+export-item = { $range } ({ $format })
+export-format-text = Text
+export-column-time = Time
+export-column-account = Account
+export-column-model = Model
+export-column-used = Used
+export-column-limit = Limit
+export-column-delta = Change
+export-column-spend = Spend
+settings = Open Cursor Settings
+quit = Exit
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file } could not be read and was backed up to { $path }
+settings-unreadable = ⚠️ { $file } could not be read, using default settings: { $error }
+settings-invalid-fields = ⚠️ Ignored invalid settings: { $fields }
+settings-reload-failed = ⚠️ { $file } has errors, keeping the current settings: { $error }
+managed-by-organization = 🔒 Managed by your organization
+policy-invalid-fields = ⚠️ Ignored invalid policy settings: { $fields }
+overridden-for-session = ⚙️ Set from the command line or environment for this run
+error-no-token = Unable to obtain access token, please ensure that Cursor is installed and logged in.
+error-no-user-id = Cannot extract user ID from Token
+error-no-usage = Unable to retrieve usage data, please check your network connection.
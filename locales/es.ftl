@@ -0,0 +1,151 @@
+# Traducción al español
+
+language-name = Español
+language-system = Usar el idioma del sistema
+
+## 菜单标题和分组
+
+menu-title = 🤖 Uso de Cursor GPT-4
+menu-language = ----- 🇺🇳 Idioma -----
+menu-refresh-interval = ----- ⏳ Intervalo de actualización -----
+menu-icon = ----- 🎨 Icono -----
+menu-options = ----- ⚙️ Opciones -----
+
+## 用量信息
+
+requests = { $count ->
+    [one] { $count } solicitud
+   *[other] { $count } solicitudes
+}
+usage-used = Usado: { $used }/{ $total } solicitudes
+usage-remaining = Restante: { requests }
+usage-rate = Uso: { $percentage } %
+usage-rate-level = Uso: { $percentage } % ({ $level })
+usage-pace-ahead = Ritmo: { $percentage } % por delante
+usage-pace-behind = Ritmo: { $percentage } % por detrás
+usage-account = Cuenta: { $email }
+usage-last-update = Última actualización: { $time }
+usage-never = nunca
+usage-stale = ⚠️ Los datos están desactualizados, última actualización correcta { $time }
+usage-refresh-failed = ❌ Falló la última actualización: { $error }
+usage-error = ❌ Error: { $error }
+
+## 用量预测
+
+forecast-rate = ~{ $rate } solicitudes/día
+forecast-current-pace = 📈 Ritmo actual: { $rate }
+forecast-runs-out = 📈 Al ritmo actual se agota el { $date } ({ $rate })
+forecast-runs-out-before-reset = 📈 Al ritmo actual se agota el { $date } ({ $days ->
+    [one] { $days } día
+   *[other] { $days } días
+} antes del reinicio)
+forecast-lasts-until-reset = 📈 Al ritmo actual alcanza hasta el reinicio ({ $rate })
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %d/%m/%Y, %H:%M
+date-month-day = %-d %b
+date-short = %d/%m
+date-day = { $weekday } { $date }
+relative-just-now = ahora mismo
+relative-minutes = hace { $count } min
+relative-hours = hace { $count } h
+relative-days = { $count ->
+    [one] hace { $count } día
+   *[other] hace { $count } días
+}
+duration-hours = { $count ->
+    [one] { $count } hora
+   *[other] { $count } horas
+}
+duration-minutes = { $count ->
+    [one] { $count } minuto
+   *[other] { $count } minutos
+}
+duration-seconds = { $count ->
+    [one] { $count } segundo
+   *[other] { $count } segundos
+}
+duration-separator = { " " }
+list-separator = { ", " }
+weekday-mon = lun
+weekday-tue = mar
+weekday-wed = mié
+weekday-thu = jue
+weekday-fri = vie
+weekday-sat = sáb
+weekday-sun = dom
+
+## 每日用量
+
+breakdown = 📊 Uso diario
+breakdown-today = Hoy: { requests }
+breakdown-yesterday = Ayer: { requests }
+breakdown-this-week = Esta semana: { requests }
+
+## 刷新间隔
+
+interval-adaptive = Adaptativo
+interval-custom = Personalizado: { $duration }
+quiet-hours = 🌙 Horas de silencio: { $when } · { $action }
+quiet-paused = en pausa
+quiet-slow = cada { $duration }
+quiet-active = { " " }(activo ahora)
+
+## 图标
+
+color-absolute = Por uso
+color-pace = Por ritmo
+skip-weekends = Omitir fines de semana en el ritmo
+style-disc = Icono de disco
+style-sparkline = Icono de línea
+style-bars = Icono de barras diarias
+levels-standard = Niveles: estándar (50/70/90 %)
+levels-relaxed = Niveles: holgados (70/85/95 %)
+levels-strict = Niveles: estrictos (30/50/75 %)
+levels-color-blind = Niveles: aptos para daltónicos
+levels-custom = Niveles: personalizados
+level-low = Bajo
+level-moderate = Moderado
+level-high = Alto
+level-critical = Crítico
+
+## 选项
+
+refresh = Actualizar datos
+pause = ⏸ Pausar monitorización
+pause-1h = Durante 1 hora
+pause-tomorrow = Hasta mañana
+pause-indefinitely = Hasta reanudar
+resume = ▶️ Reanudar monitorización
+paused-until = ⏸ Monitorización en pausa hasta { $time }
+paused = ⏸ Monitorización en pausa
+export = 📤 Exportar historial
+export-this-month = Este mes
+export-last-month = Mes pasado
+export-last-30-days = Últimos 30 días
+export-item = { $range } ({ $format })
+export-format-text = Texto
+export-column-time = Hora
+export-column-account = Cuenta
+export-column-model = Modelo
+export-column-used = Usado
+export-column-limit = Límite
+export-column-delta = Cambio
+export-column-spend = Gasto
+settings = Abrir ajustes de Cursor
+quit = Salir
+
+## 错误信息
+
+settings-corrupted = ⚠️ No se pudo leer { $file }; se guardó una copia en { $path }
+settings-unreadable = ⚠️ No se pudo leer { $file }; se usan los ajustes predeterminados: { $error }
+settings-invalid-fields = ⚠️ Se ignoraron ajustes no válidos: { $fields }
+settings-reload-failed = ⚠️ { $file } contiene errores; se mantienen los ajustes actuales: { $error }
+managed-by-organization = 🔒 Administrado por tu organización
+policy-invalid-fields = ⚠️ Se ignoraron ajustes de directiva no válidos: { $fields }
+overridden-for-session = ⚙️ Fijado por la línea de comandos o el entorno en esta ejecución
+error-no-token = No se pudo obtener el token de acceso. Asegúrate de que Cursor está instalado y de que has iniciado sesión.
+error-no-user-id = No se pudo extraer el ID de usuario del token
+error-no-usage = No se pudieron obtener los datos de uso. Comprueba tu conexión de red.
@@ -0,0 +1,133 @@
+# 日本語翻訳
+
+language-name = 日本語
+language-system = システムに従う
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 使用量
+menu-language = ----- 🇺🇳 言語 -----
+menu-refresh-interval = ----- ⏳ 更新間隔 -----
+menu-icon = ----- 🎨 アイコン -----
+menu-options = ----- ⚙️ オプション -----
+
+## 用量信息
+
+requests = { $count } リクエスト
+usage-used = 使用済み: { $used }/{ $total } リクエスト
+usage-remaining = 残り: { requests }
+usage-rate = 使用率: { $percentage }%
+usage-rate-level = 使用率: { $percentage }%（{ $level }）
+usage-pace-ahead = ペース: { $percentage }% 先行
+usage-pace-behind = ペース: { $percentage }% 遅れ
+usage-account = アカウント: { $email }
+usage-last-update = 最終更新: { $time }
+usage-never = 未取得
+usage-stale = ⚠️ データが古くなっています。最後に取得に成功したのは { $time }
+usage-refresh-failed = ❌ 前回の更新に失敗しました: { $error }
+usage-error = ❌ エラー: { $error }
+
+## 用量预测
+
+forecast-rate = 1日あたり約 { $rate } リクエスト
+forecast-current-pace = 📈 現在のペース: { $rate }
+forecast-runs-out = 📈 現在のペースでは { $date } に上限に達します（{ $rate }）
+forecast-runs-out-before-reset = 📈 現在のペースでは { $date } に上限に達します（リセットの { $days } 日前）
+forecast-lasts-until-reset = 📈 現在のペースならリセットまで足ります（{ $rate }）
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %Y年%-m月%-d日 %H:%M
+date-month-day = %-m月%-d日
+date-short = %m/%d
+date-day = { $date } ({ $weekday })
+relative-just-now = たった今
+relative-minutes = { $count } 分前
+relative-hours = { $count } 時間前
+relative-days = { $count } 日前
+duration-hours = { $count }時間
+duration-minutes = { $count }分
+duration-seconds = { $count }秒
+duration-separator = { "" }
+list-separator = 、
+weekday-mon = 月
+weekday-tue = 火
+weekday-wed = 水
+weekday-thu = 木
+weekday-fri = 金
+weekday-sat = 土
+weekday-sun = 日
+
+## 每日用量
+
+breakdown = 📊 日別の使用量
+breakdown-today = 今日: { requests }
+breakdown-yesterday = 昨日: { requests }
+breakdown-this-week = 今週: { requests }
+
+## 刷新间隔
+
+interval-adaptive = 自動調整
+interval-custom = カスタム: { $duration }
+quiet-hours = 🌙 サイレント時間: { $when } · { $action }
+quiet-paused = 更新を停止
+quiet-slow = { $duration }ごとに更新
+quiet-active = （適用中）
+
+## 图标
+
+color-absolute = 使用率で表示
+color-pace = ペースで表示
+skip-weekends = ペース計算で週末を除外
+style-disc = 円形アイコン
+style-sparkline = 折れ線アイコン
+style-bars = 日別棒グラフアイコン
+levels-standard = レベル: 標準（50/70/90%）
+levels-relaxed = レベル: 緩め（70/85/95%）
+levels-strict = レベル: 厳しめ（30/50/75%）
+levels-color-blind = レベル: 色覚多様性に配慮
+levels-custom = レベル: カスタム
+level-low = 低
+level-moderate = 中
+level-high = 高
+level-critical = 危険
+
+## 选项
+
+refresh = データを更新
+pause = ⏸ 監視を一時停止
+pause-1h = 1時間
+pause-tomorrow = 明日まで
+pause-indefinitely = 再開するまで
+resume = ▶️ 監視を再開
+paused-until = ⏸ 監視を一時停止中（再開: { $time }）
+paused = ⏸ 監視を一時停止中
+export = 📤 履歴をエクスポート
+export-this-month = 今月
+export-last-month = 先月
+export-last-30-days = 過去30日間
+export-item = { $range } ({ $format })
+export-format-text = テキスト
+export-column-time = 時刻
+export-column-account = アカウント
+export-column-model = モデル
+export-column-used = 使用量
+export-column-limit = 上限
+export-column-delta = 増加
+export-column-spend = 支出
+settings = Cursor の設定を開く
+quit = 終了
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file } を読み込めなかったため { $path } にバックアップしました
+settings-unreadable = ⚠️ { $file } を読み込めないため、既定の設定を使用しています: { $error }
+settings-invalid-fields = ⚠️ 無効な設定を無視しました: { $fields }
+settings-reload-failed = ⚠️ { $file } にエラーがあるため、現在の設定を維持します: { $error }
+managed-by-organization = 🔒 組織によって管理されています
+policy-invalid-fields = ⚠️ 無効なポリシー設定を無視しました: { $fields }
+overridden-for-session = ⚙️ コマンドラインまたは環境変数で今回の起動に限り指定されています
+error-no-token = アクセストークンを取得できません。Cursor がインストールされ、ログインしていることを確認してください。
+error-no-user-id = トークンからユーザー ID を取得できません
+error-no-usage = 使用量データを取得できません。ネットワーク接続を確認してください。
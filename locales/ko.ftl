@@ -0,0 +1,133 @@
+# 한국어 번역
+
+language-name = 한국어
+language-system = 시스템 설정 따르기
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 사용량
+menu-language = ----- 🇺🇳 언어 -----
+menu-refresh-interval = ----- ⏳ 새로 고침 간격 -----
+menu-icon = ----- 🎨 아이콘 -----
+menu-options = ----- ⚙️ 옵션 -----
+
+## 用量信息
+
+requests = 요청 { $count }회
+usage-used = 사용: { $used }/{ $total } 요청
+usage-remaining = 남음: { requests }
+usage-rate = 사용률: { $percentage }%
+usage-rate-level = 사용률: { $percentage }% ({ $level })
+usage-pace-ahead = 페이스: { $percentage }% 앞섬
+usage-pace-behind = 페이스: { $percentage }% 뒤처짐
+usage-account = 계정: { $email }
+usage-last-update = 마지막 업데이트: { $time }
+usage-never = 없음
+usage-stale = ⚠️ 데이터가 오래되었습니다. 마지막 성공: { $time }
+usage-refresh-failed = ❌ 마지막 새로 고침 실패: { $error }
+usage-error = ❌ 오류: { $error }
+
+## 用量预测
+
+forecast-rate = 하루 약 { $rate }회 요청
+forecast-current-pace = 📈 현재 페이스: { $rate }
+forecast-runs-out = 📈 현재 페이스라면 { $date }에 소진됩니다 ({ $rate })
+forecast-runs-out-before-reset = 📈 현재 페이스라면 { $date }에 소진됩니다 (초기화 { $days }일 전)
+forecast-lasts-until-reset = 📈 현재 페이스라면 초기화까지 충분합니다 ({ $rate })
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %Y년 %-m월 %-d일 %H:%M
+date-month-day = %-m월 %-d일
+date-short = %m.%d.
+date-day = { $date } ({ $weekday })
+relative-just-now = 방금 전
+relative-minutes = { $count }분 전
+relative-hours = { $count }시간 전
+relative-days = { $count }일 전
+duration-hours = { $count }시간
+duration-minutes = { $count }분
+duration-seconds = { $count }초
+duration-separator = { " " }
+list-separator = { ", " }
+weekday-mon = 월
+weekday-tue = 화
+weekday-wed = 수
+weekday-thu = 목
+weekday-fri = 금
+weekday-sat = 토
+weekday-sun = 일
+
+## 每日用量
+
+breakdown = 📊 일별 사용량
+breakdown-today = 오늘: { requests }
+breakdown-yesterday = 어제: { requests }
+breakdown-this-week = 이번 주: { requests }
+
+## 刷新间隔
+
+interval-adaptive = 자동 조절
+interval-custom = 사용자 지정: { $duration }
+quiet-hours = 🌙 방해 금지 시간: { $when } · { $action }
+quiet-paused = 새로 고침 중지
+quiet-slow = { $duration }마다 새로 고침
+quiet-active = { " " }(적용 중)
+
+## 图标
+
+color-absolute = 사용률 기준
+color-pace = 페이스 기준
+skip-weekends = 페이스 계산에서 주말 제외
+style-disc = 원형 아이콘
+style-sparkline = 꺾은선 아이콘
+style-bars = 일별 막대 아이콘
+levels-standard = 단계: 표준 (50/70/90%)
+levels-relaxed = 단계: 여유 (70/85/95%)
+levels-strict = 단계: 엄격 (30/50/75%)
+levels-color-blind = 단계: 색각 이상 친화
+levels-custom = 단계: 사용자 지정
+level-low = 낮음
+level-moderate = 보통
+level-high = 높음
+level-critical = 위험
+
+## 选项
+
+refresh = 데이터 새로 고침
+pause = ⏸ 모니터링 일시 중지
+pause-1h = 1시간
+pause-tomorrow = 내일까지
+pause-indefinitely = 다시 시작할 때까지
+resume = ▶️ 모니터링 다시 시작
+paused-until = ⏸ 모니터링 일시 중지됨 (재개: { $time })
+paused = ⏸ 모니터링 일시 중지됨
+export = 📤 기록 내보내기
+export-this-month = 이번 달
+export-last-month = 지난달
+export-last-30-days = 최근 30일
+export-item = { $range } ({ $format })
+export-format-text = 텍스트
+export-column-time = 시간
+export-column-account = 계정
+export-column-model = 모델
+export-column-used = 사용량
+export-column-limit = 한도
+export-column-delta = 증가
+export-column-spend = 지출
+settings = Cursor 설정 열기
+quit = 종료
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file }을 읽을 수 없어 { $path }에 백업했습니다
+settings-unreadable = ⚠️ { $file }을 읽을 수 없어 기본 설정을 사용합니다: { $error }
+settings-invalid-fields = ⚠️ 잘못된 설정을 무시했습니다: { $fields }
+settings-reload-failed = ⚠️ { $file }에 오류가 있어 현재 설정을 유지합니다: { $error }
+managed-by-organization = 🔒 조직에서 관리하는 설정입니다
+policy-invalid-fields = ⚠️ 잘못된 정책 설정을 무시했습니다: { $fields }
+overridden-for-session = ⚙️ 이번 실행에 한해 명령줄 또는 환경 변수로 지정되었습니다
+error-no-token = 액세스 토큰을 가져올 수 없습니다. Cursor가 설치되어 있고 로그인되어 있는지 확인하세요.
+error-no-user-id = 토큰에서 사용자 ID를 추출할 수 없습니다
+error-no-usage = 사용량 데이터를 가져올 수 없습니다. 네트워크 연결을 확인하세요.
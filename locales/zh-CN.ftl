@@ -0,0 +1,133 @@
+# 简体中文翻译
+
+language-name = 中文
+language-system = 跟随系统
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 用量
+menu-language = ----- 🇺🇳 语言 -----
+menu-refresh-interval = ----- ⏳ 刷新间隔 -----
+menu-icon = ----- 🎨 图标 -----
+menu-options = ----- ⚙️ 选项 -----
+
+## 用量信息
+
+requests = { $count } 次请求
+usage-used = 已用: { $used }/{ $total } 次请求
+usage-remaining = 剩余: { requests }
+usage-rate = 使用率: { $percentage }%
+usage-rate-level = 使用率: { $percentage }%（{ $level }）
+usage-pace-ahead = 节奏: 超前 { $percentage }%
+usage-pace-behind = 节奏: 落后 { $percentage }%
+usage-account = 账户: { $email }
+usage-last-update = 最后更新: { $time }
+usage-never = 尚未获取
+usage-stale = ⚠️ 数据已过期，最近一次成功获取于 { $time }
+usage-refresh-failed = ❌ 上次刷新失败: { $error }
+usage-error = ❌ 错误: { $error }
+
+## 用量预测
+
+forecast-rate = ~{ $rate } 次请求/天
+forecast-current-pace = 📈 当前速度：{ $rate }
+forecast-runs-out = 📈 按当前速度：{ $date } 用完（{ $rate }）
+forecast-runs-out-before-reset = 📈 按当前速度：{ $date } 用完（距重置还有 { $days } 天）
+forecast-lasts-until-reset = 📈 按当前速度：额度可用到重置（{ $rate }）
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %Y年%-m月%-d日 %H:%M
+date-month-day = %-m月%-d日
+date-short = %m-%d
+date-day = { $date } { $weekday }
+relative-just-now = 刚刚
+relative-minutes = { $count } 分钟前
+relative-hours = { $count } 小时前
+relative-days = { $count } 天前
+duration-hours = { $count }小时
+duration-minutes = { $count }分钟
+duration-seconds = { $count }秒
+duration-separator = { "" }
+list-separator = 、
+weekday-mon = 周一
+weekday-tue = 周二
+weekday-wed = 周三
+weekday-thu = 周四
+weekday-fri = 周五
+weekday-sat = 周六
+weekday-sun = 周日
+
+## 每日用量
+
+breakdown = 📊 每日用量
+breakdown-today = 今天: { requests }
+breakdown-yesterday = 昨天: { requests }
+breakdown-this-week = 本周: { requests }
+
+## 刷新间隔
+
+interval-adaptive = 自适应
+interval-custom = 自定义：{ $duration }
+quiet-hours = 🌙 静默时段：{ $when } · { $action }
+quiet-paused = 暂停刷新
+quiet-slow = 每{ $duration }刷新
+quiet-active = （生效中）
+
+## 图标
+
+color-absolute = 按使用率
+color-pace = 按消耗节奏
+skip-weekends = 节奏跳过周末
+style-disc = 圆点图标
+style-sparkline = 折线图标
+style-bars = 每日柱状图标
+levels-standard = 等级: 标准（50/70/90%）
+levels-relaxed = 等级: 宽松（70/85/95%）
+levels-strict = 等级: 严格（30/50/75%）
+levels-color-blind = 等级: 色盲友好
+levels-custom = 等级: 自定义
+level-low = 低
+level-moderate = 中
+level-high = 高
+level-critical = 严重
+
+## 选项
+
+refresh = 刷新数据
+pause = ⏸ 暂停监控
+pause-1h = 1小时
+pause-tomorrow = 直到明天
+pause-indefinitely = 直到手动恢复
+resume = ▶️ 恢复监控
+paused-until = ⏸ 监控已暂停，恢复时间 { $time }
+paused = ⏸ 监控已暂停
+export = 📤 导出历史
+export-this-month = 本月
+export-last-month = 上月
+export-last-30-days = 最近30天
+export-item = { $range } ({ $format })
+export-format-text = 文本
+export-column-time = 时间
+export-column-account = 账户
+export-column-model = 模型
+export-column-used = 已用
+export-column-limit = 上限
+export-column-delta = 增量
+export-column-spend = 花费
+settings = 打开Cursor设置
+quit = 退出
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file } 无法读取，已备份到 { $path }
+settings-unreadable = ⚠️ { $file } 无法读取，暂时使用默认设置: { $error }
+settings-invalid-fields = ⚠️ 已忽略无效的设置项: { $fields }
+settings-reload-failed = ⚠️ { $file } 有错误，继续使用当前设置: { $error }
+managed-by-organization = 🔒 由您的组织管理
+policy-invalid-fields = ⚠️ 已忽略无效的策略设置: { $fields }
+overridden-for-session = ⚙️ 本次运行由命令行或环境变量指定
+error-no-token = 无法获取访问令牌，请确认已安装并登录 Cursor。
+error-no-user-id = 无法从令牌中提取用户 ID
+error-no-usage = 无法获取用量数据，请检查网络连接。
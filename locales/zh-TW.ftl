@@ -0,0 +1,133 @@
+# 繁體中文翻譯
+
+language-name = 繁體中文
+language-system = 跟隨系統
+
+## 菜单标题和分组
+
+menu-title = 🤖 Cursor GPT-4 用量
+menu-language = ----- 🇺🇳 語言 -----
+menu-refresh-interval = ----- ⏳ 重新整理間隔 -----
+menu-icon = ----- 🎨 圖示 -----
+menu-options = ----- ⚙️ 選項 -----
+
+## 用量信息
+
+requests = { $count } 次請求
+usage-used = 已用: { $used }/{ $total } 次請求
+usage-remaining = 剩餘: { requests }
+usage-rate = 使用率: { $percentage }%
+usage-rate-level = 使用率: { $percentage }%（{ $level }）
+usage-pace-ahead = 節奏: 超前 { $percentage }%
+usage-pace-behind = 節奏: 落後 { $percentage }%
+usage-account = 帳戶: { $email }
+usage-last-update = 最後更新: { $time }
+usage-never = 尚未取得
+usage-stale = ⚠️ 資料已過期，最近一次成功取得於 { $time }
+usage-refresh-failed = ❌ 上次重新整理失敗: { $error }
+usage-error = ❌ 錯誤: { $error }
+
+## 用量预测
+
+forecast-rate = ~{ $rate } 次請求/天
+forecast-current-pace = 📈 目前速度：{ $rate }
+forecast-runs-out = 📈 依目前速度：{ $date } 用完（{ $rate }）
+forecast-runs-out-before-reset = 📈 依目前速度：{ $date } 用完（距重設還有 { $days } 天）
+forecast-lasts-until-reset = 📈 依目前速度：額度可用到重設（{ $rate }）
+
+## 时间和日期（日期格式使用 strftime 语法，月份名称按当前语言显示）
+
+time-of-day = %H:%M:%S
+date-time = %Y年%-m月%-d日 %H:%M
+date-month-day = %-m月%-d日
+date-short = %m/%d
+date-day = { $date } { $weekday }
+relative-just-now = 剛剛
+relative-minutes = { $count } 分鐘前
+relative-hours = { $count } 小時前
+relative-days = { $count } 天前
+duration-hours = { $count }小時
+duration-minutes = { $count }分鐘
+duration-seconds = { $count }秒
+duration-separator = { "" }
+list-separator = 、
+weekday-mon = 週一
+weekday-tue = 週二
+weekday-wed = 週三
+weekday-thu = 週四
+weekday-fri = 週五
+weekday-sat = 週六
+weekday-sun = 週日
+
+## 每日用量
+
+breakdown = 📊 每日用量
+breakdown-today = 今天: { requests }
+breakdown-yesterday = 昨天: { requests }
+breakdown-this-week = 本週: { requests }
+
+## 刷新间隔
+
+interval-adaptive = 自動調整
+interval-custom = 自訂：{ $duration }
+quiet-hours = 🌙 靜默時段：{ $when } · { $action }
+quiet-paused = 暫停重新整理
+quiet-slow = 每{ $duration }重新整理
+quiet-active = （生效中）
+
+## 图标
+
+color-absolute = 依使用率
+color-pace = 依消耗節奏
+skip-weekends = 節奏略過週末
+style-disc = 圓點圖示
+style-sparkline = 折線圖示
+style-bars = 每日長條圖示
+levels-standard = 等級: 標準（50/70/90%）
+levels-relaxed = 等級: 寬鬆（70/85/95%）
+levels-strict = 等級: 嚴格（30/50/75%）
+levels-color-blind = 等級: 色盲友善
+levels-custom = 等級: 自訂
+level-low = 低
+level-moderate = 中
+level-high = 高
+level-critical = 嚴重
+
+## 选项
+
+refresh = 重新整理資料
+pause = ⏸ 暫停監控
+pause-1h = 1小時
+pause-tomorrow = 直到明天
+pause-indefinitely = 直到手動恢復
+resume = ▶️ 恢復監控
+paused-until = ⏸ 監控已暫停，恢復時間 { $time }
+paused = ⏸ 監控已暫停
+export = 📤 匯出歷史
+export-this-month = 本月
+export-last-month = 上月
+export-last-30-days = 最近30天
+export-item = { $range } ({ $format })
+export-format-text = 文字
+export-column-time = 時間
+export-column-account = 帳戶
+export-column-model = 模型
+export-column-used = 已用
+export-column-limit = 上限
+export-column-delta = 增量
+export-column-spend = 花費
+settings = 開啟Cursor設定
+quit = 結束
+
+## 错误信息
+
+settings-corrupted = ⚠️ { $file } 無法讀取，已備份到 { $path }
+settings-unreadable = ⚠️ { $file } 無法讀取，暫時使用預設設定: { $error }
+settings-invalid-fields = ⚠️ 已忽略無效的設定項目: { $fields }
+settings-reload-failed = ⚠️ { $file } 有錯誤，繼續使用目前設定: { $error }
+managed-by-organization = 🔒 由您的組織管理
+policy-invalid-fields = ⚠️ 已忽略無效的原則設定: { $fields }
+overridden-for-session = ⚙️ 本次執行由命令列或環境變數指定
+error-no-token = 無法取得存取權杖，請確認已安裝並登入 Cursor。
+error-no-user-id = 無法從權杖中擷取使用者 ID
+error-no-usage = 無法取得用量資料，請檢查網路連線。
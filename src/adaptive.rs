@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use parking_lot::Mutex;
+
+use crate::config::SharedState;
+use crate::events::StateEvent;
+
+// 用量在这段时间内有变化时视为正在使用，按最短间隔刷新
+const ACTIVE_WINDOW: Duration = Duration::from_secs(10 * 60);
+// 用量每保持不变这么久，刷新间隔翻倍
+const BACKOFF_STEP: Duration = Duration::from_secs(15 * 60);
+// 距离下一个阈值不足这么多个百分点时按最短间隔刷新
+const THRESHOLD_MARGIN: f32 = 5.0;
+
+// 最近一次用量变化的情况，只反映 Cursor 的用量，与键盘鼠标是否空闲无关
+#[derive(Default)]
+struct UsageActivity {
+    last_used: Option<i32>,
+    last_change: Option<Instant>,
+    percentage: f32,
+}
+
+// 自适应刷新：根据用量变化情况在最短和最长间隔之间调整
+// 不检测系统空闲，用量保持不变就逐步放慢，即使用户仍在操作电脑
+#[derive(Clone)]
+pub struct AdaptiveScheduler {
+    activity: Arc<Mutex<UsageActivity>>,
+}
+
+impl AdaptiveScheduler {
+    // 订阅用量更新事件，记录用量最后一次变化的时间
+    pub fn attach(state: &SharedState) -> Self {
+        let scheduler = Self { activity: Arc::new(Mutex::new(UsageActivity::default())) };
+
+        let activity = scheduler.activity.clone();
+        state.subscribe(move |event| {
+            let StateEvent::UsageUpdated(change) = event else {
+                return;
+            };
+            if change.new.error.is_some() || !change.new.has_snapshot() {
+                return;
+            }
+            let mut activity = activity.lock();
+            if activity.last_used != Some(change.new.used) {
+                // 第一次拿到数据时也按“刚刚变化”处理，启动后先快速刷新一段时间
+                activity.last_used = Some(change.new.used);
+                activity.last_change = Some(Instant::now());
+            }
+            activity.percentage = change.new.percentage;
+        });
+
+        scheduler
+    }
+
+    pub fn next_interval(&self, min: Duration, max: Duration, thresholds: &[f32]) -> Duration {
+        let activity = self.activity.lock();
+
+        // 接近阈值时尽快发现越线
+        let near_threshold = thresholds
+            .iter()
+            .any(|&t| activity.percentage < t && t - activity.percentage <= THRESHOLD_MARGIN);
+
+        // 还没有拿到数据时按最短间隔重试
+        let Some(last_change) = activity.last_change else {
+            return min;
+        };
+
+        // 用量保持不变的时长
+        let flat_for = last_change.elapsed();
+        if near_threshold || flat_for < ACTIVE_WINDOW {
+            return min;
+        }
+
+        let steps = ((flat_for - ACTIVE_WINDOW).as_secs() / BACKOFF_STEP.as_secs()).min(16) as u32 + 1;
+        min.saturating_mul(2u32.saturating_pow(steps)).clamp(min, max)
+    }
+}
@@ -7,13 +7,20 @@ use serde::Deserialize;
 use serde::Serialize;
 use ureq::typestate::WithoutBody;
 use ureq::RequestBuilder;
+use chrono::DateTime;
+use chrono::Local;
+use chrono::Utc;
+use std::collections::BTreeMap;
 use std::time::Duration;
 
+use crate::config::ModelUsage;
+use crate::config::PRIMARY_MODEL;
 use crate::config::UsageData;
+use crate::config::UsageError;
 use crate::utils::TokenExtractor;
 
 #[derive(Debug, Deserialize)]
-pub struct Gpt4Usage {
+pub struct ApiModelUsage {
     #[serde(rename = "numRequests")]
     pub num_requests: Option<i32>,
     #[serde(rename = "maxRequestUsage")]
@@ -22,8 +29,32 @@ pub struct Gpt4Usage {
 
 #[derive(Debug, Deserialize)]
 struct ApiUsageResponse {
-    #[serde(rename = "gpt-4")]
-    gpt4: Option<Gpt4Usage>,
+    #[serde(rename = "startOfMonth")]
+    start_of_month: Option<String>,
+    // 其余字段按模型名称索引，例如 "gpt-4"、"gpt-3.5-turbo"
+    #[serde(flatten)]
+    models: BTreeMap<String, serde_json::Value>,
+}
+
+impl ApiUsageResponse {
+    fn model_usages(&self) -> Vec<ModelUsage> {
+        self.models
+            .iter()
+            .filter_map(|(name, value)| {
+                let usage: ApiModelUsage = serde_json::from_value(value.clone()).ok()?;
+                Some(ModelUsage {
+                    model: name.clone(),
+                    used: usage.num_requests.unwrap_or(0),
+                    limit: usage.max_request_usage,
+                })
+            })
+            .collect()
+    }
+
+    fn start_of_month(&self) -> Option<DateTime<Utc>> {
+        let raw = self.start_of_month.as_deref()?;
+        DateTime::parse_from_rfc3339(raw).ok().map(|dt| dt.with_timezone(&Utc))
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -143,7 +174,7 @@ impl CursorClient {
         Ok(None)
     }
 
-    fn get_usage(&self) -> Result<Option<Gpt4Usage>> {
+    fn get_usage(&self) -> Result<Option<ApiUsageResponse>> {
         if let Some(cookie) = self.build_cookie() {
             let url = "https://www.cursor.com/api/usage";
             let cookie_clone = cookie.clone();
@@ -160,7 +191,7 @@ impl CursorClient {
             match result {
                 Ok(mut response) if response.status() == 200 => {
                     let usage: ApiUsageResponse = response.body_mut().read_json()?;
-                    return Ok(usage.gpt4);
+                    return Ok(Some(usage));
                 }
                 Ok(_) => return Ok(None),
                 Err(e) => return Err(anyhow::anyhow!("Failed after retries: {}", e)),
@@ -170,19 +201,17 @@ impl CursorClient {
     }
 
     pub fn fetch_usage_data(&mut self) -> Result<UsageData> {
-        let mut usage_data = UsageData::default();
+        let mut usage_data = UsageData { last_attempt: Some(Local::now()), ..UsageData::default() };
 
         // 尝试获取token
         if !self.get_token()? {
-            usage_data.error = Some(
-                "Unable to obtain access token, please ensure that Cursor is installed and logged in.".to_string(),
-            );
+            usage_data.error = Some(UsageError::NoToken);
             return Ok(usage_data);
         }
 
         // 提取用户ID
         if !self.extract_user_id()? {
-            usage_data.error = Some("Cannot extract user ID from Token".to_string());
+            usage_data.error = Some(UsageError::NoUserId);
             return Ok(usage_data);
         }
 
@@ -194,14 +223,17 @@ impl CursorClient {
         // 获取用量数据
         match self.get_usage() {
             Ok(Some(usage)) => {
-                usage_data.used = usage.num_requests.unwrap_or(0);
-                usage_data.total = usage.max_request_usage.unwrap_or(0);
+                usage_data.models = usage.model_usages();
+                usage_data.start_of_month = usage.start_of_month();
+                if let Some(primary) = usage_data.models.iter().find(|m| m.model == PRIMARY_MODEL) {
+                    usage_data.used = primary.used;
+                    usage_data.total = primary.limit.unwrap_or(0);
+                }
                 usage_data.percentage = usage_data.calculate_percentage();
-                usage_data.update_time();
+                usage_data.mark_success();
             }
             _ => {
-                usage_data.error =
-                    Some("Unable to retrieve usage data, please check your network connection.".to_string());
+                usage_data.error = Some(UsageError::NoUsage);
             }
         }
 
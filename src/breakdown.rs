@@ -0,0 +1,93 @@
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+
+use crate::config::PRIMARY_MODEL;
+use crate::history::HistorySample;
+
+// 按天统计的最近天数
+pub const BREAKDOWN_DAYS: i64 = 7;
+
+// 某一天的请求数
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DailyUsage {
+    pub date: NaiveDate,
+    pub requests: i32,
+}
+
+// 按天/周汇总的用量
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsageBreakdown {
+    pub today: i32,
+    pub yesterday: i32,
+    pub this_week: i32,
+    // 最近 BREAKDOWN_DAYS 天，按日期升序，最后一项为今天
+    pub days: Vec<DailyUsage>,
+}
+
+impl UsageBreakdown {
+    // 统计需要的最早日期（再往前一天的采样作为基准）
+    pub fn first_day(today: NaiveDate) -> NaiveDate {
+        let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        week_start.min(today - Duration::days(BREAKDOWN_DAYS - 1)) - Duration::days(1)
+    }
+
+    pub fn compute(samples: &[HistorySample], today: NaiveDate) -> Self {
+        let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let first_day = today - Duration::days(BREAKDOWN_DAYS - 1);
+        let mut days: Vec<DailyUsage> = (0..BREAKDOWN_DAYS)
+            .map(|offset| DailyUsage { date: first_day + Duration::days(offset), requests: 0 })
+            .collect();
+        let mut this_week = 0;
+
+        for (date, delta) in daily_deltas(samples) {
+            if date >= week_start && date <= today {
+                this_week += delta;
+            }
+            if let Some(day) = days.iter_mut().find(|d| d.date == date) {
+                day.requests += delta;
+            }
+        }
+
+        let requests_on = |date: NaiveDate| days.iter().find(|d| d.date == date).map_or(0, |d| d.requests);
+        Self {
+            today: requests_on(today),
+            yesterday: requests_on(today - Duration::days(1)),
+            this_week,
+            days,
+        }
+    }
+
+    pub fn max_daily(&self) -> i32 {
+        self.days.iter().map(|d| d.requests).max().unwrap_or(0)
+    }
+}
+
+// 相邻两次成功采样之间新增的请求数，记在后一次采样的日期上
+fn daily_deltas(samples: &[HistorySample]) -> Vec<(NaiveDate, i32)> {
+    let points: Vec<(&HistorySample, i32)> = samples
+        .iter()
+        .filter(|s| s.error.is_none())
+        .filter_map(|s| Some((s, s.model(PRIMARY_MODEL)?.used)))
+        .collect();
+
+    points
+        .windows(2)
+        .filter(|pair| pair[0].0.account == pair[1].0.account)
+        .map(|pair| {
+            let (prev, next) = (pair[0].1, pair[1].1);
+            // 用量回落说明计费周期已重置，重置后的用量全部算作新增
+            let delta = if next >= prev { next - prev } else { next };
+            (pair[1].0.timestamp.date_naive(), delta)
+        })
+        .collect()
+}
+
+// 简单的文本柱状图
+pub fn text_bar(value: i32, max: i32, width: usize) -> String {
+    if max <= 0 || value <= 0 {
+        return String::new();
+    }
+    let filled = ((value as f64 / max as f64) * width as f64).round().max(1.0) as usize;
+    "█".repeat(filled.min(width))
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use anyhow::bail;
+use chrono::Duration;
+use chrono::Local;
+use chrono::NaiveDate;
+
+use crate::config::Language;
+use crate::config::LanguagePreference;
+use crate::export::ExportFormat;
+use crate::export::ExportRange;
+use crate::export::default_export_path;
+use crate::export::export_history;
+use crate::history::HistoryStore;
+use crate::i18n::system_language;
+use crate::settings::Settings;
+use crate::settings::get_config_path;
+
+pub const USAGE: &str = "Usage:
+  cursor_bar [--config PATH] [--set FIELD=VALUE]...
+  cursor_bar export [--format csv|jsonl|txt] [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--output PATH]";
+
+// 命令行子命令
+pub enum Command {
+    // 默认启动托盘
+    Run(RunArgs),
+    Export(ExportArgs),
+    // -h 或 --help，由 main 输出用法
+    Help,
+}
+
+#[derive(Default)]
+pub struct RunArgs {
+    // 代替默认位置的设置文件，扩展名为 .toml 时按 TOML 读写
+    pub config: Option<PathBuf>,
+    // 覆盖单个设置字段，优先级高于设置文件和环境变量
+    pub overrides: Vec<(String, String)>,
+}
+
+pub struct ExportArgs {
+    pub format: ExportFormat,
+    pub from: NaiveDate,
+    // 包含在导出范围内的最后一天
+    pub to: NaiveDate,
+    pub output: Option<PathBuf>,
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command> {
+    match args.next() {
+        None => Ok(Command::Run(RunArgs::default())),
+        Some(command) if command == "export" => parse_export_args(args).map(Command::Export),
+        Some(flag) if flag == "-h" || flag == "--help" => Ok(Command::Help),
+        Some(flag) if flag.starts_with("--") => parse_run_args(std::iter::once(flag).chain(args)).map(Command::Run),
+        Some(other) => bail!("Unknown command: {}\n{}", other, USAGE),
+    }
+}
+
+fn parse_run_args<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs> {
+    let mut run = RunArgs::default();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| anyhow!("Missing value for {}\n{}", flag, USAGE))?;
+        match flag.as_str() {
+            "--config" => run.config = Some(PathBuf::from(value)),
+            "--set" => {
+                let (field, value) = value.split_once('=').ok_or_else(|| anyhow!("Expected FIELD=VALUE for --set: {}", value))?;
+                run.overrides.push((field.trim().to_string(), value.to_string()));
+            }
+            _ => bail!("Unknown option: {}\n{}", flag, USAGE),
+        }
+    }
+    Ok(run)
+}
+
+fn parse_export_args<I: Iterator<Item = String>>(mut args: I) -> Result<ExportArgs> {
+    let (from, to) = ExportRange::ThisMonth.dates(Local::now().date_naive());
+    let mut export = ExportArgs { format: ExportFormat::Csv, from, to: to - Duration::days(1), output: None };
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| anyhow!("Missing value for {}\n{}", flag, USAGE))?;
+        match flag.as_str() {
+            "--format" => {
+                export.format = ExportFormat::from_str(&value).ok_or_else(|| anyhow!("Unknown export format: {}", value))?
+            }
+            "--from" => export.from = parse_date(&value)?,
+            "--to" => export.to = parse_date(&value)?,
+            "--output" => export.output = Some(PathBuf::from(value)),
+            _ => bail!("Unknown option: {}\n{}", flag, USAGE),
+        }
+    }
+
+    if export.from > export.to {
+        bail!("--from must not be later than --to");
+    }
+    Ok(export)
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").with_context(|| format!("Invalid date: {}", value))
+}
+
+pub fn run_export(args: ExportArgs) -> Result<()> {
+    let store = HistoryStore::open()?;
+    let end = args.to + Duration::days(1);
+    let path = match args.output {
+        Some(path) => path,
+        None => default_export_path(args.from, end, args.format)?,
+    };
+
+    let count = export_history(&store, args.from, end, args.format, export_language(), &path)?;
+    println!("Exported {} rows to {}", count, path.display());
+    Ok(())
+}
+
+// 文本导出使用设置中的语言，设置文件无法读取时跟随系统
+fn export_language() -> Language {
+    match get_config_path().and_then(|path| Settings::read(&path)) {
+        Ok((settings, _)) => LanguagePreference::from_str(&settings.language).resolve(),
+        Err(_) => system_language(),
+    }
+}
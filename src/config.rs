@@ -3,10 +3,81 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 
 use anyhow::Result;
+use chrono::DateTime;
 use chrono::Local;
+use chrono::Months;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::api::CursorClient;
+use crate::events::Change;
+use crate::events::EventBus;
+use crate::events::StateEvent;
+use crate::events::SubscriptionId;
+use crate::history::HistoryStore;
+use crate::i18n::system_language;
+use crate::levels::LevelPreset;
+use crate::levels::UsageLevels;
+use crate::power::PowerState;
+use crate::process::CursorClosedAction;
+use crate::schedule::QuietAction;
+use crate::schedule::QuietSchedule;
 use crate::settings::Settings;
+use crate::settings::SettingsWarning;
+
+// 菜单和图标展示的主模型
+pub const PRIMARY_MODEL: &str = "gpt-4";
+
+// 超过多少个刷新间隔没有成功获取数据时视为过期
+pub const STALE_INTERVALS: u64 = 3;
+
+// 单个模型的用量
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelUsage {
+    pub model: String,
+    pub used: i32,
+    pub limit: Option<i32>,
+}
+
+// 获取用量失败的原因，保存的是消息 ID 而不是翻译后的文本，显示时按当前语言翻译
+#[derive(Clone, Debug, PartialEq)]
+pub enum UsageError {
+    NoToken,
+    NoUserId,
+    NoUsage,
+    // 其他错误的原始描述，旧版本历史中保存的文本也按原样显示
+    Other(String),
+}
+
+impl UsageError {
+    pub fn from_str(error: &str) -> Self {
+        match error {
+            "error-no-token" => UsageError::NoToken,
+            "error-no-user-id" => UsageError::NoUserId,
+            "error-no-usage" => UsageError::NoUsage,
+            _ => UsageError::Other(error.to_string()),
+        }
+    }
+
+    pub fn describe(&self, lang: Language) -> String {
+        match self {
+            UsageError::Other(message) => message.clone(),
+            _ => lang.tr(&self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UsageError::NoToken => write!(f, "error-no-token"),
+            UsageError::NoUserId => write!(f, "error-no-user-id"),
+            UsageError::NoUsage => write!(f, "error-no-usage"),
+            UsageError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
 
 // 用于在UI中显示的用量数据
 #[derive(Clone, Debug, PartialEq)]
@@ -15,8 +86,16 @@ pub struct UsageData {
     pub total: i32,
     pub percentage: f32,
     pub email: Option<String>,
-    pub last_update: String,
-    pub error: Option<String>,
+    // 最近一次尝试获取的时间，从未获取时为 None
+    pub last_attempt: Option<DateTime<Local>>,
+    // 最近一次成功获取的时间
+    pub last_success: Option<DateTime<Local>>,
+    pub error: Option<UsageError>,
+    pub models: Vec<ModelUsage>,
+    // 当前计费周期的开始时间
+    pub start_of_month: Option<DateTime<Utc>>,
+    // 按量计费的消费金额（美元），当前 API 未提供时为 None
+    pub spend: Option<f64>,
 }
 
 impl Default for UsageData {
@@ -26,8 +105,12 @@ impl Default for UsageData {
             total: 0,
             percentage: 0.0,
             email: None,
-            last_update: Local::now().format("%H:%M:%S").to_string(),
+            last_attempt: None,
+            last_success: None,
             error: None,
+            models: Vec::new(),
+            start_of_month: None,
+            spend: None,
         }
     }
 }
@@ -40,26 +123,95 @@ impl UsageData {
         ((self.used as f32 / self.total as f32) * 100.0 * 10.0).round() / 10.0
     }
 
-    pub fn update_time(&mut self) {
-        self.last_update = Local::now().format("%H:%M:%S").to_string();
+    // 当前计费周期的起止时间（本地时区）
+    pub fn billing_cycle(&self) -> Option<(DateTime<Local>, DateTime<Local>)> {
+        let start = self.start_of_month?.with_timezone(&Local);
+        let end = start.checked_add_months(Months::new(1))?;
+        Some((start, end))
+    }
+
+    // 是否有可展示的用量数据（曾经成功获取过）
+    pub fn has_snapshot(&self) -> bool {
+        self.last_success.is_some()
+    }
+
+    // 合并一次刷新结果：刷新失败时保留上次成功的数据，只更新错误和尝试时间
+    pub fn merge_refresh(self, next: UsageData) -> UsageData {
+        if next.error.is_some() && self.has_snapshot() {
+            UsageData { error: next.error, last_attempt: next.last_attempt, ..self }
+        } else {
+            next
+        }
+    }
+
+    pub fn mark_success(&mut self) {
+        self.last_success = self.last_attempt.or_else(|| Some(Local::now()));
+    }
+
+    // 最近一次成功获取距今超过若干个刷新间隔时，视为数据过期
+    pub fn is_stale(&self, interval_secs: u64, now: DateTime<Local>) -> bool {
+        let Some(last_success) = self.last_success else {
+            return false;
+        };
+        let max_age = chrono::Duration::seconds((interval_secs * STALE_INTERVALS) as i64);
+        now - last_success > max_age
     }
 }
 
 // 语言枚举类型
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Language {
     Chinese,
     English,
+    TraditionalChinese,
+    Japanese,
+    Korean,
+    German,
+    Spanish,
 }
 
 impl Language {
-    pub fn from_str(lang: &str) -> Self {
+    pub fn from_str(lang: &str) -> Option<Self> {
         match lang {
-            "Chinese" => Language::Chinese,
-            "English" => Language::English,
-            _ => Language::Chinese,
+            "Chinese" => Some(Language::Chinese),
+            "English" => Some(Language::English),
+            "TraditionalChinese" => Some(Language::TraditionalChinese),
+            "Japanese" => Some(Language::Japanese),
+            "Korean" => Some(Language::Korean),
+            "German" => Some(Language::German),
+            "Spanish" => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    // BCP 47 语言标签，同时也是翻译文件名
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Language::Chinese => "zh-CN",
+            Language::English => "en",
+            Language::TraditionalChinese => "zh-TW",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::German => "de",
+            Language::Spanish => "es",
         }
     }
+
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|lang| lang.tag() == tag)
+    }
+
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::Chinese,
+            Language::English,
+            Language::TraditionalChinese,
+            Language::Japanese,
+            Language::Korean,
+            Language::German,
+            Language::Spanish,
+        ]
+    }
 }
 
 impl fmt::Display for Language {
@@ -67,57 +219,226 @@ impl fmt::Display for Language {
         match self {
             Language::Chinese => write!(f, "Chinese"),
             Language::English => write!(f, "English"),
+            Language::TraditionalChinese => write!(f, "TraditionalChinese"),
+            Language::Japanese => write!(f, "Japanese"),
+            Language::Korean => write!(f, "Korean"),
+            Language::German => write!(f, "German"),
+            Language::Spanish => write!(f, "Spanish"),
         }
     }
 }
 
+// 语言设置：跟随系统或固定为某种语言
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LanguagePreference {
+    System,
+    Fixed(Language),
+}
+
+impl LanguagePreference {
+    // 无法识别的值按跟随系统处理
+    pub fn from_str(pref: &str) -> Self {
+        Language::from_str(pref).map_or(LanguagePreference::System, LanguagePreference::Fixed)
+    }
+
+    // 跟随系统时每次都重新读取系统语言
+    pub fn resolve(&self) -> Language {
+        match self {
+            LanguagePreference::System => system_language(),
+            LanguagePreference::Fixed(lang) => *lang,
+        }
+    }
+}
+
+impl fmt::Display for LanguagePreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LanguagePreference::System => write!(f, "System"),
+            LanguagePreference::Fixed(lang) => write!(f, "{}", lang),
+        }
+    }
+}
+
+// 图标颜色模式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorMode {
+    // 按使用率的绝对值着色
+    Absolute,
+    // 按相对计费周期理想消耗速度的超前程度着色
+    Pace,
+}
+
+impl ColorMode {
+    pub fn from_str(mode: &str) -> Self {
+        match mode {
+            "Pace" => ColorMode::Pace,
+            _ => ColorMode::Absolute,
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorMode::Absolute => write!(f, "Absolute"),
+            ColorMode::Pace => write!(f, "Pace"),
+        }
+    }
+}
+
+// 菜单中账户邮箱的显示方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountDisplay {
+    Full,
+    // 只保留用户名的首字符和域名，例如 j***@example.com
+    Redacted,
+    Hidden,
+}
+
+impl AccountDisplay {
+    pub fn from_str(display: &str) -> Self {
+        match display {
+            "Redacted" => AccountDisplay::Redacted,
+            "Hidden" => AccountDisplay::Hidden,
+            _ => AccountDisplay::Full,
+        }
+    }
+
+    // 按显示方式处理邮箱，Hidden 时不显示
+    pub fn apply(&self, email: &str) -> Option<String> {
+        match self {
+            AccountDisplay::Full => Some(email.to_string()),
+            AccountDisplay::Redacted => {
+                let (name, domain) = email.split_once('@').unwrap_or((email, ""));
+                let first = name.chars().next().map(String::from).unwrap_or_default();
+                if domain.is_empty() { Some(format!("{}***", first)) } else { Some(format!("{}***@{}", first, domain)) }
+            }
+            AccountDisplay::Hidden => None,
+        }
+    }
+}
+
+impl fmt::Display for AccountDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountDisplay::Full => write!(f, "Full"),
+            AccountDisplay::Redacted => write!(f, "Redacted"),
+            AccountDisplay::Hidden => write!(f, "Hidden"),
+        }
+    }
+}
+
+// 托盘图标样式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IconStyle {
+    // 纯色圆点
+    Disc,
+    // 最近若干次采样的用量折线
+    Sparkline,
+    // 最近7天每天请求数的柱状图
+    Bars,
+}
+
+impl IconStyle {
+    pub fn from_str(style: &str) -> Self {
+        match style {
+            "Sparkline" => IconStyle::Sparkline,
+            "Bars" => IconStyle::Bars,
+            _ => IconStyle::Disc,
+        }
+    }
+
+    pub fn all() -> &'static [IconStyle] {
+        &[IconStyle::Disc, IconStyle::Sparkline, IconStyle::Bars]
+    }
+}
+
+impl fmt::Display for IconStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IconStyle::Disc => write!(f, "Disc"),
+            IconStyle::Sparkline => write!(f, "Sparkline"),
+            IconStyle::Bars => write!(f, "Bars"),
+        }
+    }
+}
+
+// 暂停监控的状态，保存在设置中以便重启后继续生效
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PauseState {
+    Until(DateTime<Local>),
+    Indefinitely,
+}
+
+impl PauseState {
+    pub fn is_active(&self, now: DateTime<Local>) -> bool {
+        match self {
+            PauseState::Until(until) => *until > now,
+            PauseState::Indefinitely => true,
+        }
+    }
+}
+
+// 自定义刷新间隔的取值范围（秒）
+pub const MIN_INTERVAL_SECS: u64 = 30;
+pub const MAX_INTERVAL_SECS: u64 = 24 * 3600;
+
 // 刷新间隔枚举
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RefreshInterval {
-    Min1 = 60,
-    Min5 = 300,
-    Min10 = 600,
-    Min30 = 1800,
-    Hour1 = 3600,
+    // 根据用量变化在最短和最长间隔之间自动调整
+    Adaptive,
+    Min1,
+    Min5,
+    Min10,
+    Min30,
+    Hour1,
+    // 手工编辑 settings.json 设置的任意间隔（秒）
+    Custom(u64),
 }
 
 impl RefreshInterval {
     pub fn from_secs(secs: u64) -> Self {
         match secs {
+            0 => Self::Adaptive,
             60 => Self::Min1,
             300 => Self::Min5,
             600 => Self::Min10,
             1800 => Self::Min30,
             3600 => Self::Hour1,
-            _ => Self::Min5,
+            // 超出范围的值收敛到边界，而不是悄悄改成默认值
+            _ => {
+                let clamped = secs.clamp(MIN_INTERVAL_SECS, MAX_INTERVAL_SECS);
+                if clamped == secs { Self::Custom(secs) } else { Self::from_secs(clamped) }
+            }
         }
     }
 
     pub fn as_secs(&self) -> u64 {
-        *self as u64
+        match self {
+            RefreshInterval::Adaptive => 0,
+            RefreshInterval::Min1 => 60,
+            RefreshInterval::Min5 => 300,
+            RefreshInterval::Min10 => 600,
+            RefreshInterval::Min30 => 1800,
+            RefreshInterval::Hour1 => 3600,
+            RefreshInterval::Custom(secs) => *secs,
+        }
     }
 
-    pub fn to_string(self, lang: Language) -> &'static str {
-        match lang {
-            Language::Chinese => match self {
-                RefreshInterval::Min1 => "1分钟",
-                RefreshInterval::Min5 => "5分钟",
-                RefreshInterval::Min10 => "10分钟",
-                RefreshInterval::Min30 => "30分钟",
-                RefreshInterval::Hour1 => "1小时",
-            },
-            Language::English => match self {
-                RefreshInterval::Min1 => "1 minute",
-                RefreshInterval::Min5 => "5 minutes",
-                RefreshInterval::Min10 => "10 minutes",
-                RefreshInterval::Min30 => "30 minutes",
-                RefreshInterval::Hour1 => "1 hour",
-            },
+    pub fn to_string(self, lang: Language) -> String {
+        match self {
+            RefreshInterval::Adaptive => lang.tr("interval-adaptive"),
+            RefreshInterval::Custom(secs) => {
+                lang.tr_args("interval-custom", &[("duration", format_duration(secs, lang).into())])
+            }
+            _ => format_duration(self.as_secs(), lang),
         }
     }
 
     pub fn all() -> &'static [RefreshInterval] {
         &[
+            RefreshInterval::Adaptive,
             RefreshInterval::Min1,
             RefreshInterval::Min5,
             RefreshInterval::Min10,
@@ -127,51 +448,180 @@ impl RefreshInterval {
     }
 }
 
+// 将秒数格式化为 "1小时30分钟" / "1 hour 30 minutes"
+pub fn format_duration(secs: u64, lang: Language) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+    let mut parts = Vec::new();
+    for (count, id) in [(hours, "duration-hours"), (minutes, "duration-minutes")] {
+        if count > 0 {
+            parts.push(lang.tr_args(id, &[("count", count.into())]));
+        }
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(lang.tr_args("duration-seconds", &[("count", seconds.into())]));
+    }
+    parts.join(&lang.tr("duration-separator"))
+}
+
 // 共享状态
 #[derive(Clone)]
 pub struct SharedState {
     usage_data: Arc<Mutex<UsageData>>,
-    language: Arc<Mutex<Language>>,
+    language: Arc<Mutex<LanguagePreference>>,
     refresh_interval: Arc<Mutex<RefreshInterval>>,
+    color_mode: Arc<Mutex<ColorMode>>,
+    skip_weekends: Arc<Mutex<bool>>,
+    icon_style: Arc<Mutex<IconStyle>>,
+    level_preset: Arc<Mutex<LevelPreset>>,
+    pause: Arc<Mutex<Option<PauseState>>>,
+    // 由刷新线程中的 CursorWatcher 更新
+    cursor_running: Arc<Mutex<bool>>,
+    // 由刷新线程每次醒来时读取，界面线程只读缓存的值
+    power_state: Arc<Mutex<PowerState>>,
     settings: Arc<Mutex<Settings>>,
+    settings_warning: Arc<Mutex<Option<SettingsWarning>>>,
+    history: Option<Arc<HistoryStore>>,
+    events: Arc<EventBus>,
 }
 
 impl SharedState {
     pub fn new() -> Self {
         // 尝试加载设置，如果失败则使用默认设置
-        let settings = match Settings::load() {
-            Ok(s) => s,
+        let (settings, settings_warning) = match Settings::load() {
+            Ok(loaded) => loaded,
             Err(e) => {
                 eprintln!("Failed to load settings: {}", e);
-                Settings::default()
+                (Settings::default(), Some(SettingsWarning::Unreadable(e.to_string())))
             }
         };
 
-        let language = Language::from_str(&settings.language);
+        let language = LanguagePreference::from_str(&settings.language);
         let refresh_interval = RefreshInterval::from_secs(settings.refresh_interval);
+        let color_mode = ColorMode::from_str(&settings.color_mode);
+        let skip_weekends = settings.pace_skip_weekends;
+        let icon_style = IconStyle::from_str(&settings.icon_style);
+        let level_preset = LevelPreset::from_str(&settings.level_preset);
+        let pause = settings.pause;
+
+        // 历史数据库不可用时仍然可以正常显示当前用量
+        let history = match HistoryStore::open() {
+            Ok(store) => Some(Arc::new(store)),
+            Err(e) => {
+                eprintln!("Failed to open usage history: {}", e);
+                None
+            }
+        };
+
+        // 启动时先展示上次成功获取的数据
+        let usage_data = history
+            .as_ref()
+            .and_then(|h| h.latest_success().ok().flatten())
+            .map(|sample| sample.to_usage_data())
+            .unwrap_or_default();
 
         Self {
-            usage_data: Arc::new(Mutex::new(UsageData::default())),
+            usage_data: Arc::new(Mutex::new(usage_data)),
             language: Arc::new(Mutex::new(language)),
             refresh_interval: Arc::new(Mutex::new(refresh_interval)),
+            color_mode: Arc::new(Mutex::new(color_mode)),
+            skip_weekends: Arc::new(Mutex::new(skip_weekends)),
+            icon_style: Arc::new(Mutex::new(icon_style)),
+            level_preset: Arc::new(Mutex::new(level_preset)),
+            pause: Arc::new(Mutex::new(pause)),
+            cursor_running: Arc::new(Mutex::new(true)),
+            power_state: Arc::new(Mutex::new(PowerState::default())),
             settings: Arc::new(Mutex::new(settings)),
+            settings_warning: Arc::new(Mutex::new(settings_warning)),
+            history,
+            events: Arc::new(EventBus::default()),
         }
     }
 
+    // 订阅状态变更事件，监听器在发布事件的线程上调用
+    pub fn subscribe<F>(&self, listener: F) -> SubscriptionId
+    where
+        F: Fn(&StateEvent) + Send + Sync + 'static,
+    {
+        self.events.subscribe(listener)
+    }
+
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        self.events.unsubscribe(id);
+    }
+
+    pub fn history(&self) -> Option<Arc<HistoryStore>> {
+        self.history.clone()
+    }
+
     pub fn get_usage_data(&self) -> UsageData {
         self.usage_data.lock().clone()
     }
 
     pub fn set_usage_data(&self, data: UsageData) {
-        *self.usage_data.lock() = data;
+        let old = std::mem::replace(&mut *self.usage_data.lock(), data.clone());
+        let thresholds = self.get_usage_levels(&data).thresholds();
+        for event in StateEvent::from_usage_change(&old, &data, &thresholds) {
+            self.events.publish(&event);
+        }
+    }
+
+    // 读取设置文件时发现的问题
+    pub fn get_settings_warning(&self) -> Option<SettingsWarning> {
+        self.settings_warning.lock().clone()
     }
 
+    fn set_settings_warning(&self, warning: Option<SettingsWarning>) {
+        let old = std::mem::replace(&mut *self.settings_warning.lock(), warning.clone());
+        if old != warning {
+            self.events.publish(&StateEvent::SettingsWarningChanged(Change { old, new: warning }));
+        }
+    }
+
+    // 设置文件被外部修改后重新应用，解析失败时保留当前设置并在菜单中提示
+    pub fn reload_settings(&self, parsed: Result<(Settings, Vec<String>)>) {
+        let (new, invalid) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Failed to reload settings: {}", e);
+                self.set_settings_warning(Some(SettingsWarning::ReloadFailed(e.to_string())));
+                return;
+            }
+        };
+
+        // 与 save_settings 相同，持有设置锁时同步各个字段
+        let mut settings = self.settings.lock();
+        let old = std::mem::replace(&mut *settings, new.clone());
+        if old != new {
+            self.apply_settings(&new);
+        }
+        drop(settings);
+
+        // 启动时备份损坏文件的提示一直保留，其他警告按本次读取的结果更新
+        let warning = if !invalid.is_empty() {
+            eprintln!("Ignoring invalid settings: {}", invalid.join(", "));
+            Some(SettingsWarning::InvalidFields(invalid))
+        } else {
+            self.get_settings_warning().filter(|w| matches!(w, SettingsWarning::Corrupted { .. }))
+        };
+        self.set_settings_warning(warning);
+
+        if old != new {
+            self.events.publish(&StateEvent::SettingsChanged(Change { old, new }));
+        }
+    }
+
+    // 实际使用的语言
     pub fn get_language(&self) -> Language {
+        self.language.lock().resolve()
+    }
+
+    pub fn get_language_preference(&self) -> LanguagePreference {
         *self.language.lock()
     }
 
-    pub fn set_language(&self, lang: Language) {
-        *self.language.lock() = lang;
+    pub fn set_language(&self, pref: LanguagePreference) {
+        *self.language.lock() = pref;
         self.save_settings();
     }
 
@@ -184,22 +634,221 @@ impl SharedState {
         self.save_settings();
     }
 
+    // 自适应模式的最短和最长刷新间隔（秒）
+    pub fn get_adaptive_bounds(&self) -> (u64, u64) {
+        let settings = self.settings.lock();
+        (settings.adaptive_min_secs, settings.adaptive_max_secs)
+    }
+
+    pub fn get_quiet_schedule(&self) -> Option<QuietSchedule> {
+        self.settings.lock().quiet_schedule.clone()
+    }
+
+    // 按电源状态限制的最短刷新间隔（秒）
+    fn get_power_interval_secs(&self) -> Option<u64> {
+        let power = *self.power_state.lock();
+        let settings = self.settings.lock();
+        let battery = if power.on_battery { settings.battery_interval_secs } else { 0 };
+        let low_power = if power.low_power { settings.low_power_interval_secs } else { 0 };
+        Some(battery.max(low_power)).filter(|&secs| secs > 0)
+    }
+
+    pub fn get_account_display(&self) -> AccountDisplay {
+        AccountDisplay::from_str(&self.settings.lock().account_display)
+    }
+
+    // Cursor 未运行时的轮询策略和放慢后的间隔（秒）
+    pub fn get_cursor_closed_policy(&self) -> (CursorClosedAction, u64) {
+        let settings = self.settings.lock();
+        (settings.cursor_closed_action, settings.cursor_closed_interval_secs)
+    }
+
+    pub fn set_cursor_running(&self, running: bool) {
+        *self.cursor_running.lock() = running;
+    }
+
+    pub fn set_power_state(&self, power: PowerState) {
+        *self.power_state.lock() = power;
+    }
+
+    // 当前是否暂停自动刷新，以及两次刷新之间的间隔（秒）
+    // 自适应模式下由刷新线程传入当前的间隔，未传入时按最长间隔计算
+    pub fn poll_policy(&self, adaptive_secs: Option<u64>) -> (bool, u64) {
+        let quiet = self.get_quiet_schedule().filter(|s| s.is_quiet(Local::now()));
+        let (closed_action, closed_interval) = self.get_cursor_closed_policy();
+        let cursor_closed = !*self.cursor_running.lock();
+
+        // 手动暂停、静默时段或 Cursor 未运行且策略为暂停时不自动刷新
+        let paused = self.is_paused()
+            || matches!(&quiet, Some(s) if s.action == QuietAction::Pause)
+            || (cursor_closed && closed_action == CursorClosedAction::Pause);
+
+        let mut interval = match self.get_refresh_interval() {
+            RefreshInterval::Adaptive => adaptive_secs.unwrap_or(self.get_adaptive_bounds().1),
+            interval => interval.as_secs(),
+        };
+        if let Some(s) = quiet {
+            interval = interval.max(s.slow_interval_secs);
+        }
+        if cursor_closed && closed_action == CursorClosedAction::Slow {
+            interval = interval.max(closed_interval);
+        }
+        // 使用电池或低电量模式时放慢轮询
+        if let Some(power_interval) = self.get_power_interval_secs() {
+            interval = interval.max(power_interval);
+        }
+        (paused, interval)
+    }
+
+    // 按当前的轮询策略，两次刷新之间的最长间隔（秒），用于判断数据是否过期；暂停轮询时为 None
+    pub fn get_expected_interval_secs(&self) -> Option<u64> {
+        let (paused, interval) = self.poll_policy(None);
+        (!paused).then_some(interval)
+    }
+
+    pub fn get_color_mode(&self) -> ColorMode {
+        *self.color_mode.lock()
+    }
+
+    pub fn set_color_mode(&self, mode: ColorMode) {
+        *self.color_mode.lock() = mode;
+        self.save_settings();
+    }
+
+    pub fn get_skip_weekends(&self) -> bool {
+        *self.skip_weekends.lock()
+    }
+
+    pub fn set_skip_weekends(&self, skip: bool) {
+        *self.skip_weekends.lock() = skip;
+        self.save_settings();
+    }
+
+    pub fn get_icon_style(&self) -> IconStyle {
+        *self.icon_style.lock()
+    }
+
+    pub fn set_icon_style(&self, style: IconStyle) {
+        *self.icon_style.lock() = style;
+        self.save_settings();
+    }
+
+    pub fn get_level_preset(&self) -> LevelPreset {
+        *self.level_preset.lock()
+    }
+
+    pub fn set_level_preset(&self, preset: LevelPreset) {
+        *self.level_preset.lock() = preset;
+        self.save_settings();
+    }
+
+    // settings.json 中是否定义了自定义等级
+    pub fn has_custom_levels(&self) -> bool {
+        !self.settings.lock().usage_levels.is_empty()
+    }
+
+    // 图标展示的用量所使用的等级：先匹配按模型或账户设置的规则，其次是选中的预设
+    pub fn get_usage_levels(&self, data: &UsageData) -> UsageLevels {
+        let lang = self.get_language();
+        let preset = self.get_level_preset();
+        let settings = self.settings.lock();
+
+        if let Some(rule) = settings.usage_level_rules.iter().find(|r| r.matches(data.email.as_deref(), PRIMARY_MODEL)) {
+            return UsageLevels::new(rule.levels.clone());
+        }
+        match preset {
+            LevelPreset::Custom if !settings.usage_levels.is_empty() => UsageLevels::new(settings.usage_levels.clone()),
+            preset => UsageLevels::new(preset.levels(lang)),
+        }
+    }
+
+    // 当前生效的暂停状态，已过期的暂停会被清除
+    // 已经到期的暂停按未暂停处理，由刷新线程通过 clear_expired_pause 清除
+    pub fn get_pause(&self) -> Option<PauseState> {
+        self.pause.lock().filter(|p| p.is_active(Local::now()))
+    }
+
+    // 清除已经到期的暂停并保存设置
+    pub fn clear_expired_pause(&self) {
+        let expired = {
+            let mut pause = self.pause.lock();
+            let expired = pause.is_some_and(|p| !p.is_active(Local::now()));
+            if expired {
+                *pause = None;
+            }
+            expired
+        };
+        if expired {
+            self.save_settings();
+        }
+    }
+
+    pub fn set_pause(&self, pause: Option<PauseState>) {
+        *self.pause.lock() = pause;
+        self.save_settings();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.get_pause().is_some()
+    }
+
     pub fn update_usage_data(&self) -> Result<()> {
         let mut client = CursorClient::new();
-        let data = client.fetch_usage_data()?;
-        self.set_usage_data(data);
+        let data = match client.fetch_usage_data() {
+            Ok(data) => data,
+            Err(e) => UsageData { last_attempt: Some(Local::now()), error: Some(UsageError::Other(e.to_string())), ..UsageData::default() },
+        };
+        // 失败的刷新没有用量数据，不写入历史
+        if data.error.is_none()
+            && let Some(history) = &self.history
+            && let Err(e) = history.record(&data)
+        {
+            eprintln!("Failed to record usage history: {}", e);
+        }
+        // 只有刷新线程会调用这里，刷新结果按完成顺序依次合并
+        let current = self.get_usage_data();
+        self.set_usage_data(current.merge_refresh(data));
         Ok(())
     }
 
     fn save_settings(&self) {
         let mut settings = self.settings.lock();
-        settings.language = self.get_language().to_string();
-        settings.refresh_interval = self.get_refresh_interval().as_secs();
+        let old = settings.clone();
+        let mut ours = old.clone();
+        // 只写回与设置中的取值不同的字段，避免把文件中的原始写法（例如超出范围的间隔）规范化后写入
+        let language = self.get_language_preference();
+        if language != LanguagePreference::from_str(&old.language) {
+            ours.language = language.to_string();
+        }
+        let refresh_interval = self.get_refresh_interval();
+        if refresh_interval != RefreshInterval::from_secs(old.refresh_interval) {
+            ours.refresh_interval = refresh_interval.as_secs();
+        }
+        let color_mode = self.get_color_mode();
+        if color_mode != ColorMode::from_str(&old.color_mode) {
+            ours.color_mode = color_mode.to_string();
+        }
+        ours.pace_skip_weekends = self.get_skip_weekends();
+        let icon_style = self.get_icon_style();
+        if icon_style != IconStyle::from_str(&old.icon_style) {
+            ours.icon_style = icon_style.to_string();
+        }
+        let level_preset = self.get_level_preset();
+        if level_preset != LevelPreset::from_str(&old.level_preset) {
+            ours.level_preset = level_preset.to_string();
+        }
+        ours.pause = *self.pause.lock();
 
         // 尝试保存设置，失败时重试一次
+        let mut new = ours.clone();
+        let mut warning = None;
         for attempt in 1..=2 {
-            match settings.save() {
-                Ok(_) => break,
+            match ours.save(&old) {
+                Ok((merged, corrupted)) => {
+                    new = merged;
+                    warning = corrupted;
+                    break;
+                }
                 Err(e) => {
                     eprintln!("Failed to save settings (attempt {}): {}", attempt, e);
                     if attempt == 2 {
@@ -208,5 +857,31 @@ impl SharedState {
                 }
             }
         }
+        *settings = new.clone();
+
+        // 释放锁后再发布，监听器可能会读取设置
+        drop(settings);
+        // 合并进来的其他实例的修改同样需要生效
+        if new != ours {
+            self.apply_settings(&new);
+        }
+        if old != new {
+            self.events.publish(&StateEvent::SettingsChanged(Change { old, new }));
+        }
+        // 保存时发现磁盘上的文件损坏并已备份
+        if warning.is_some() {
+            self.set_settings_warning(warning);
+        }
+    }
+
+    // 把设置文件中的取值同步到各个字段
+    fn apply_settings(&self, settings: &Settings) {
+        *self.language.lock() = LanguagePreference::from_str(&settings.language);
+        *self.refresh_interval.lock() = RefreshInterval::from_secs(settings.refresh_interval);
+        *self.color_mode.lock() = ColorMode::from_str(&settings.color_mode);
+        *self.skip_weekends.lock() = settings.pace_skip_weekends;
+        *self.icon_style.lock() = IconStyle::from_str(&settings.icon_style);
+        *self.level_preset.lock() = LevelPreset::from_str(&settings.level_preset);
+        *self.pause.lock() = settings.pause;
     }
 }
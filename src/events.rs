@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use parking_lot::Mutex;
+
+use crate::config::UsageData;
+use crate::config::UsageError;
+use crate::settings::Settings;
+use crate::settings::SettingsWarning;
+
+// 变更前后的值
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+// SharedState 发布的事件，发布频率很低，不必为变体大小装箱
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum StateEvent {
+    // 每次刷新完成后发布，包括刷新失败
+    UsageUpdated(Change<UsageData>),
+    // 使用率越过当前用量等级的某个阈值（上升或下降）
+    ThresholdCrossed { threshold: f32, percentage: Change<f32> },
+    ErrorStarted(Change<Option<UsageError>>),
+    ErrorCleared(Change<Option<UsageError>>),
+    AccountChanged(Change<Option<String>>),
+    SettingsChanged(Change<Settings>),
+    SettingsWarningChanged(Change<Option<SettingsWarning>>),
+}
+
+impl StateEvent {
+    // 根据一次用量更新推导出需要发布的全部事件
+    pub fn from_usage_change(old: &UsageData, new: &UsageData, thresholds: &[f32]) -> Vec<StateEvent> {
+        let mut events = vec![StateEvent::UsageUpdated(Change { old: old.clone(), new: new.clone() })];
+
+        // 只比较两次都有数据的情况，避免启动时从0跳变触发
+        if old.has_snapshot() && new.has_snapshot() {
+            for &threshold in thresholds {
+                if (old.percentage >= threshold) != (new.percentage >= threshold) {
+                    events.push(StateEvent::ThresholdCrossed {
+                        threshold,
+                        percentage: Change { old: old.percentage, new: new.percentage },
+                    });
+                }
+            }
+        }
+
+        let error = Change { old: old.error.clone(), new: new.error.clone() };
+        match (&old.error, &new.error) {
+            (None, Some(_)) => events.push(StateEvent::ErrorStarted(error)),
+            (Some(_), None) => events.push(StateEvent::ErrorCleared(error)),
+            _ => {}
+        }
+
+        // 刷新失败时不知道账户，不视为切换
+        if new.error.is_none() && old.email != new.email {
+            events.push(StateEvent::AccountChanged(Change { old: old.email.clone(), new: new.email.clone() }));
+        }
+        events
+    }
+}
+
+pub type SubscriptionId = u64;
+
+type Listener = Arc<dyn Fn(&StateEvent) + Send + Sync>;
+
+// 简单的事件总线，监听器在发布事件的线程上同步调用
+#[derive(Default)]
+pub struct EventBus {
+    listeners: Mutex<Vec<(SubscriptionId, Listener)>>,
+    next_id: AtomicU64,
+}
+
+impl EventBus {
+    pub fn subscribe<F>(&self, listener: F) -> SubscriptionId
+    where
+        F: Fn(&StateEvent) + Send + Sync + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.listeners.lock().push((id, Arc::new(listener)));
+        id
+    }
+
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        self.listeners.lock().retain(|(listener_id, _)| *listener_id != id);
+    }
+
+    pub fn publish(&self, event: &StateEvent) {
+        // 先复制监听器列表再调用，监听器内部可以安全地订阅或取消订阅
+        let listeners: Vec<Listener> = self.listeners.lock().iter().map(|(_, l)| l.clone()).collect();
+        for listener in listeners {
+            listener(event);
+        }
+    }
+}
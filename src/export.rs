@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::Local;
+use chrono::Months;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::config::Language;
+use crate::history::HistorySample;
+use crate::history::HistoryStore;
+use crate::settings::get_config_dir;
+
+// 导出文件格式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    JsonLines,
+    // 按当前语言格式化的文本表格，供人阅读
+    Text,
+}
+
+impl ExportFormat {
+    pub fn from_str(format: &str) -> Option<Self> {
+        match format {
+            "csv" => Some(ExportFormat::Csv),
+            "jsonl" | "json-lines" => Some(ExportFormat::JsonLines),
+            "txt" | "text" => Some(ExportFormat::Text),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Text => "txt",
+        }
+    }
+}
+
+// 菜单中可选的导出范围
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportRange {
+    ThisMonth,
+    LastMonth,
+    Last30Days,
+}
+
+impl ExportRange {
+    pub fn all() -> &'static [ExportRange] {
+        &[ExportRange::ThisMonth, ExportRange::LastMonth, ExportRange::Last30Days]
+    }
+
+    // 返回 [from, to) 日期区间
+    pub fn dates(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let month_start = today.with_day(1).unwrap_or(today);
+        match self {
+            ExportRange::ThisMonth => (month_start, today + Duration::days(1)),
+            ExportRange::LastMonth => (month_start.checked_sub_months(Months::new(1)).unwrap_or(month_start), month_start),
+            ExportRange::Last30Days => (today - Duration::days(29), today + Duration::days(1)),
+        }
+    }
+}
+
+// 导出的一行：一次采样中的一个模型
+#[derive(Debug, Serialize)]
+pub struct ExportRow {
+    pub timestamp: String,
+    pub account: Option<String>,
+    pub model: String,
+    pub used: i32,
+    pub limit: Option<i32>,
+    pub delta: i32,
+    pub spend: Option<f64>,
+}
+
+pub fn export_rows(samples: &[HistorySample]) -> Vec<ExportRow> {
+    let mut previous: HashMap<(Option<String>, String), i32> = HashMap::new();
+    let mut rows = Vec::new();
+
+    for sample in samples.iter().filter(|s| s.error.is_none()) {
+        for model in &sample.models {
+            let key = (sample.account.clone(), model.model.clone());
+            // 第一条记录没有基准，用量回落说明计费周期已重置
+            let delta = match previous.insert(key, model.used) {
+                Some(prev) if model.used >= prev => model.used - prev,
+                Some(_) => model.used,
+                None => 0,
+            };
+            rows.push(ExportRow {
+                timestamp: sample.timestamp.to_rfc3339(),
+                account: sample.account.clone(),
+                model: model.model.clone(),
+                used: model.used,
+                limit: model.limit,
+                delta,
+                spend: sample.spend,
+            });
+        }
+    }
+    rows
+}
+
+// CSV 和 JSON Lines 与语言无关，只有文本表格按 lang 格式化
+pub fn write_rows<W: Write>(rows: &[ExportRow], format: ExportFormat, lang: Language, mut writer: W) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            writeln!(writer, "timestamp,account,model,used,limit,delta,spend")?;
+            for row in rows {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{}",
+                    row.timestamp,
+                    csv_field(row.account.as_deref().unwrap_or_default()),
+                    csv_field(&row.model),
+                    row.used,
+                    row.limit.map(|l| l.to_string()).unwrap_or_default(),
+                    row.delta,
+                    row.spend.map(|s| s.to_string()).unwrap_or_default(),
+                )?;
+            }
+        }
+        ExportFormat::JsonLines => {
+            for row in rows {
+                serde_json::to_writer(&mut writer, row)?;
+                writeln!(writer)?;
+            }
+        }
+        ExportFormat::Text => {
+            let header: Vec<String> = TEXT_COLUMNS.iter().map(|id| lang.tr(id)).collect();
+            writeln!(writer, "{}", header.join("\t"))?;
+            for row in rows {
+                let time = match DateTime::parse_from_rfc3339(&row.timestamp) {
+                    Ok(time) => lang.format_time(time.with_timezone(&Local), "date-time"),
+                    Err(_) => row.timestamp.clone(),
+                };
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    time,
+                    row.account.as_deref().unwrap_or_default(),
+                    row.model,
+                    lang.format_number(row.used as f64, 0),
+                    row.limit.map(|l| lang.format_number(l as f64, 0)).unwrap_or_default(),
+                    lang.format_number(row.delta as f64, 0),
+                    row.spend.map(|s| lang.format_number(s, 2)).unwrap_or_default(),
+                )?;
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// 文本表格的列标题
+const TEXT_COLUMNS: [&str; 7] = [
+    "export-column-time",
+    "export-column-account",
+    "export-column-model",
+    "export-column-used",
+    "export-column-limit",
+    "export-column-delta",
+    "export-column-spend",
+];
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// 导出 [from, to) 日期区间内的历史，返回写入的行数
+pub fn export_history(store: &HistoryStore, from: NaiveDate, to: NaiveDate, format: ExportFormat, lang: Language, path: &Path) -> Result<usize> {
+    let samples = store.samples_between(local_midnight(from)?, local_midnight(to)?)?;
+    let rows = export_rows(&samples);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    write_rows(&rows, format, lang, BufWriter::new(File::create(path)?))?;
+    Ok(rows.len())
+}
+
+// 菜单导出：按范围导出到默认位置，返回导出文件路径
+pub fn export_range(store: &HistoryStore, range: ExportRange, format: ExportFormat, lang: Language) -> Result<PathBuf> {
+    let (from, to) = range.dates(Local::now().date_naive());
+    let path = default_export_path(from, to, format)?;
+    export_history(store, from, to, format, lang, &path)?;
+    Ok(path)
+}
+
+// 默认导出到下载目录，没有下载目录时放在配置目录下
+pub fn default_export_path(from: NaiveDate, to: NaiveDate, format: ExportFormat) -> Result<PathBuf> {
+    let dir = match dirs::download_dir() {
+        Some(dir) => dir,
+        None => get_config_dir()?.join("exports"),
+    };
+    let last_day = to - Duration::days(1);
+    Ok(dir.join(format!("cursorbar-usage-{}-{}.{}", from, last_day, format.extension())))
+}
+
+fn local_midnight(date: NaiveDate) -> Result<DateTime<Local>> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|dt| dt.and_local_timezone(Local).earliest())
+        .ok_or_else(|| anyhow!("Invalid local date: {}", date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_export_is_formatted_for_language() {
+        let row = ExportRow {
+            timestamp: "2025-05-01T08:30:00+00:00".to_string(),
+            account: None,
+            model: "gpt-4".to_string(),
+            used: 12345,
+            limit: None,
+            delta: 0,
+            spend: Some(1234.5),
+        };
+        let mut output = Vec::new();
+        write_rows(&[row], ExportFormat::Text, Language::German, &mut output).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Zeit\tKonto\tModell\tVerbraucht\tLimit\tÄnderung\tAusgaben");
+        let columns: Vec<&str> = lines[1].split('\t').collect();
+        assert_eq!(&columns[1..], ["", "gpt-4", "12.345", "", "0", "1.234,50"]);
+    }
+}
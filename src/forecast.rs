@@ -0,0 +1,90 @@
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Local;
+
+use crate::config::PRIMARY_MODEL;
+use crate::config::UsageData;
+use crate::history::HistorySample;
+
+// 计算当前速度时只看最近这段时间
+const PACE_WINDOW_DAYS: i64 = 7;
+// 历史跨度太短时结果不可靠，改用整个周期的平均速度
+const MIN_HISTORY_SPAN_HOURS: i64 = 6;
+
+// 额度耗尽预测
+#[derive(Clone, Debug, PartialEq)]
+pub struct Forecast {
+    pub requests_per_day: f64,
+    // 按当前速度用完额度的时间，速度为0时为 None
+    pub exhausted_at: Option<DateTime<Local>>,
+    // 下个计费周期开始的时间，未知时为 None
+    pub reset_at: Option<DateTime<Local>>,
+}
+
+impl Forecast {
+    // 历史采样的起始时间：计费周期开始与速度窗口中较晚的一个
+    pub fn window_start(data: &UsageData, now: DateTime<Local>) -> DateTime<Local> {
+        let window_start = now - Duration::days(PACE_WINDOW_DAYS);
+        match data.billing_cycle() {
+            Some((start, _)) if start > window_start => start,
+            _ => window_start,
+        }
+    }
+
+    pub fn compute(data: &UsageData, samples: &[HistorySample], now: DateTime<Local>) -> Option<Self> {
+        if !data.has_snapshot() || data.total <= 0 || data.used >= data.total {
+            return None;
+        }
+
+        let cycle = data.billing_cycle();
+        let start = cycle.map(|(start, _)| start);
+        let reset_at = cycle.map(|(_, end)| end);
+
+        let requests_per_day = rate_from_history(data, samples, Self::window_start(data, now), now)
+            .or_else(|| {
+                // 计费周期开始时用量为0，用周期平均速度兜底
+                let elapsed = now - start?;
+                (elapsed > Duration::zero()).then(|| data.used as f64 / days(elapsed))
+            })?;
+
+        let remaining = (data.total - data.used) as f64;
+        let exhausted_at = (requests_per_day > 0.0)
+            .then(|| now + Duration::seconds((remaining / requests_per_day * 86400.0) as i64));
+
+        Some(Self { requests_per_day, exhausted_at, reset_at })
+    }
+
+    // 按当前速度是否会在重置前用完
+    pub fn overruns(&self) -> bool {
+        matches!((self.exhausted_at, self.reset_at), (Some(exhausted), Some(reset)) if exhausted < reset)
+    }
+
+    // 用完时距离重置还有多少天
+    pub fn days_before_reset(&self) -> Option<i64> {
+        let (exhausted, reset) = (self.exhausted_at?, self.reset_at?);
+        Some((reset.date_naive() - exhausted.date_naive()).num_days())
+    }
+}
+
+fn rate_from_history(
+    data: &UsageData,
+    samples: &[HistorySample],
+    window_start: DateTime<Local>,
+    now: DateTime<Local>,
+) -> Option<f64> {
+    // 只使用同一账户在窗口内的成功采样
+    let first = samples
+        .iter()
+        .filter(|s| s.error.is_none() && s.timestamp >= window_start && s.account == data.email)
+        .find_map(|s| Some((s.timestamp, s.model(PRIMARY_MODEL)?.used)))?;
+
+    let span = now - first.0;
+    if span < Duration::hours(MIN_HISTORY_SPAN_HOURS) || data.used < first.1 {
+        return None;
+    }
+    Some((data.used - first.1) as f64 / days(span))
+}
+
+fn days(duration: Duration) -> f64 {
+    duration.num_seconds() as f64 / 86400.0
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Local;
+use chrono::TimeZone;
+use parking_lot::Mutex;
+use rusqlite::Connection;
+use rusqlite::params;
+
+use crate::config::ModelUsage;
+use crate::config::PRIMARY_MODEL;
+use crate::config::UsageData;
+use crate::config::UsageError;
+use crate::settings::get_config_dir;
+
+// 采样保留的天数，超过的在打开数据库时删除
+const RETENTION_DAYS: i64 = 400;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS samples (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    timestamp INTEGER NOT NULL,
+    account TEXT,
+    spend REAL,
+    error TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_samples_timestamp ON samples (timestamp);
+CREATE TABLE IF NOT EXISTS sample_models (
+    sample_id INTEGER NOT NULL REFERENCES samples (id) ON DELETE CASCADE,
+    model TEXT NOT NULL,
+    used INTEGER NOT NULL,
+    max_requests INTEGER,
+    PRIMARY KEY (sample_id, model)
+);
+";
+
+// 历史记录中的一次采样
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistorySample {
+    pub id: i64,
+    pub timestamp: DateTime<Local>,
+    pub account: Option<String>,
+    pub models: Vec<ModelUsage>,
+    pub spend: Option<f64>,
+    pub error: Option<UsageError>,
+}
+
+impl HistorySample {
+    pub fn model(&self, name: &str) -> Option<&ModelUsage> {
+        self.models.iter().find(|m| m.model == name)
+    }
+
+    // 还原为可直接展示的用量数据，用于启动时恢复上次的结果
+    pub fn to_usage_data(&self) -> UsageData {
+        let mut data = UsageData {
+            email: self.account.clone(),
+            last_attempt: Some(self.timestamp),
+            last_success: self.error.is_none().then_some(self.timestamp),
+            error: self.error.clone(),
+            models: self.models.clone(),
+            spend: self.spend,
+            ..UsageData::default()
+        };
+        if let Some(primary) = self.model(PRIMARY_MODEL) {
+            data.used = primary.used;
+            data.total = primary.limit.unwrap_or(0);
+        }
+        data.percentage = data.calculate_percentage();
+        data
+    }
+}
+
+// 本地 SQLite 用量历史
+pub struct HistoryStore {
+    conn: Mutex<Connection>,
+}
+
+impl HistoryStore {
+    pub fn open() -> Result<Self> {
+        Self::open_at(&get_config_dir()?.join("history.db"))
+    }
+
+    pub fn open_at(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Cannot open history database")?;
+        conn.execute_batch("PRAGMA foreign_keys = ON;").context("Failed to enable foreign keys")?;
+        conn.execute_batch(SCHEMA).context("Failed to initialize history schema")?;
+        let store = Self { conn: Mutex::new(conn) };
+        store.prune_before(Local::now() - Duration::days(RETENTION_DAYS))?;
+        Ok(store)
+    }
+
+    // 删除 before 之前的采样，模型用量随外键级联删除
+    pub fn prune_before(&self, before: DateTime<Local>) -> Result<usize> {
+        let conn = self.conn.lock();
+        let deleted = conn
+            .execute("DELETE FROM samples WHERE timestamp < ?1", params![before.timestamp()])
+            .context("Failed to prune usage history")?;
+        Ok(deleted)
+    }
+
+    // 记录一次采样，返回新记录的ID
+    pub fn record(&self, data: &UsageData) -> Result<i64> {
+        self.record_at(data, data.last_attempt.unwrap_or_else(Local::now))
+    }
+
+    pub fn record_at(&self, data: &UsageData, timestamp: DateTime<Local>) -> Result<i64> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO samples (timestamp, account, spend, error) VALUES (?1, ?2, ?3, ?4)",
+            params![timestamp.timestamp(), data.email, data.spend, data.error.as_ref().map(|e| e.to_string())],
+        )?;
+        let sample_id = tx.last_insert_rowid();
+
+        for model in &data.models {
+            tx.execute(
+                "INSERT INTO sample_models (sample_id, model, used, max_requests) VALUES (?1, ?2, ?3, ?4)",
+                params![sample_id, model.model, model.used, model.limit],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(sample_id)
+    }
+
+    // 查询 [from, to) 区间内的采样，按时间升序
+    pub fn samples_between(&self, from: DateTime<Local>, to: DateTime<Local>) -> Result<Vec<HistorySample>> {
+        self.query_samples(
+            "SELECT id, timestamp, account, spend, error FROM samples
+             WHERE timestamp >= ?1 AND timestamp < ?2 ORDER BY timestamp, id",
+            params![from.timestamp(), to.timestamp()],
+        )
+    }
+
+    pub fn samples_since(&self, from: DateTime<Local>) -> Result<Vec<HistorySample>> {
+        self.query_samples(
+            "SELECT id, timestamp, account, spend, error FROM samples
+             WHERE timestamp >= ?1 ORDER BY timestamp, id",
+            params![from.timestamp()],
+        )
+    }
+
+    // 最近的 limit 条采样，按时间升序
+    pub fn latest_samples(&self, limit: usize) -> Result<Vec<HistorySample>> {
+        let mut samples = self.query_samples(
+            "SELECT id, timestamp, account, spend, error FROM samples
+             ORDER BY timestamp DESC, id DESC LIMIT ?1",
+            params![limit as i64],
+        )?;
+        samples.reverse();
+        Ok(samples)
+    }
+
+    pub fn latest_success(&self) -> Result<Option<HistorySample>> {
+        let samples = self.query_samples(
+            "SELECT id, timestamp, account, spend, error FROM samples
+             WHERE error IS NULL ORDER BY timestamp DESC, id DESC LIMIT 1",
+            params![],
+        )?;
+        Ok(samples.into_iter().next())
+    }
+
+    fn query_samples(&self, sql: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<HistorySample>> {
+        let conn = self.conn.lock();
+
+        let mut stmt = conn.prepare(sql).context("Failed to prepare history query")?;
+        let mut samples = stmt
+            .query_map(params, |row| {
+                let timestamp: i64 = row.get(1)?;
+                Ok(HistorySample {
+                    id: row.get(0)?,
+                    timestamp: Local.timestamp_opt(timestamp, 0).single().unwrap_or_else(Local::now),
+                    account: row.get(2)?,
+                    models: Vec::new(),
+                    spend: row.get(3)?,
+                    error: row.get::<_, Option<String>>(4)?.map(|e| UsageError::from_str(&e)),
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        if samples.is_empty() {
+            return Ok(samples);
+        }
+
+        // 一次性加载这批采样对应的模型用量
+        let min_id = samples.iter().map(|s| s.id).min().unwrap_or_default();
+        let max_id = samples.iter().map(|s| s.id).max().unwrap_or_default();
+        let mut stmt = conn.prepare(
+            "SELECT sample_id, model, used, max_requests FROM sample_models
+             WHERE sample_id BETWEEN ?1 AND ?2 ORDER BY sample_id, model",
+        )?;
+        let mut models: BTreeMap<i64, Vec<ModelUsage>> = BTreeMap::new();
+        let rows = stmt.query_map(params![min_id, max_id], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                ModelUsage { model: row.get(1)?, used: row.get(2)?, limit: row.get(3)? },
+            ))
+        })?;
+        for row in rows {
+            let (sample_id, usage) = row?;
+            models.entry(sample_id).or_default().push(usage);
+        }
+
+        for sample in &mut samples {
+            sample.models = models.remove(&sample.id).unwrap_or_default();
+        }
+        Ok(samples)
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::sync::OnceLock;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::Weekday;
+use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentResource;
+use fluent_bundle::FluentValue;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::types::FluentNumber;
+use fluent_bundle::types::FluentNumberOptions;
+use intl_memoizer::Memoizable;
+use intl_memoizer::concurrent::IntlLangMemoizer;
+use unic_langid::LanguageIdentifier;
+
+use crate::config::Language;
+use crate::forecast::Forecast;
+use crate::settings::get_config_dir;
+
+// 内置翻译，编译时打包进程序
+const BUILTIN_LOCALES: &[(Language, &str)] = &[
+    (Language::English, include_str!("../locales/en.ftl")),
+    (Language::Chinese, include_str!("../locales/zh-CN.ftl")),
+    (Language::TraditionalChinese, include_str!("../locales/zh-TW.ftl")),
+    (Language::Japanese, include_str!("../locales/ja.ftl")),
+    (Language::Korean, include_str!("../locales/ko.ftl")),
+    (Language::German, include_str!("../locales/de.ftl")),
+    (Language::Spanish, include_str!("../locales/es.ftl")),
+];
+
+type Bundle = FluentBundle<FluentResource>;
+
+static BUNDLES: OnceLock<HashMap<Language, Bundle>> = OnceLock::new();
+
+// 首次使用时加载全部语言，配置目录下 locales/<语言>.ftl 中的条目会覆盖内置翻译
+fn bundles() -> &'static HashMap<Language, Bundle> {
+    BUNDLES.get_or_init(|| {
+        let overrides = get_config_dir().ok().map(|dir| dir.join("locales"));
+        BUILTIN_LOCALES
+            .iter()
+            .map(|&(lang, source)| {
+                let langid: LanguageIdentifier = lang.tag().parse().expect("Invalid built-in language tag");
+                let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+                // 菜单文本不需要 Unicode 隔离字符
+                bundle.set_use_isolating(false);
+                bundle.set_formatter(Some(format_value));
+                bundle.add_resource_overriding(parse_resource(source.to_string(), lang.tag()));
+
+                if let Some(path) = overrides.as_ref().map(|dir| dir.join(format!("{}.ftl", lang.tag())))
+                    && let Ok(source) = fs::read_to_string(&path)
+                {
+                    bundle.add_resource_overriding(parse_resource(source, &path.display().to_string()));
+                }
+                (lang, bundle)
+            })
+            .collect()
+    })
+}
+
+// 解析出错时保留能解析的条目
+fn parse_resource(source: String, name: &str) -> FluentResource {
+    FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+        eprintln!("Failed to parse locale file {}: {:?}", name, errors);
+        resource
+    })
+}
+
+// 按语言格式化消息参数中的数字
+fn format_value(value: &FluentValue, intls: &IntlLangMemoizer) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+    intls.with_try_get::<NumberFormat, _, _>((), |format| format.format_fluent(number)).ok()
+}
+
+// 固定小数位数的数字参数，例如百分比
+pub fn fixed(value: f64, digits: usize) -> FluentValue<'static> {
+    let options = FluentNumberOptions {
+        minimum_fraction_digits: Some(digits),
+        maximum_fraction_digits: Some(digits),
+        ..FluentNumberOptions::default()
+    };
+    FluentValue::Number(FluentNumber::new(value, options))
+}
+
+// 千位分隔符和小数点
+#[derive(Clone, Copy, Debug)]
+pub struct NumberFormat {
+    group: char,
+    decimal: char,
+    // 整数部分至少有这么多位时才分组，例如西班牙语中 1234 不分组
+    min_grouped_len: usize,
+}
+
+impl NumberFormat {
+    pub fn for_language(lang: Language) -> Self {
+        match lang {
+            Language::German => Self { group: '.', decimal: ',', min_grouped_len: 4 },
+            Language::Spanish => Self { group: '.', decimal: ',', min_grouped_len: 5 },
+            _ => Self { group: ',', decimal: '.', min_grouped_len: 4 },
+        }
+    }
+
+    pub fn format(&self, value: f64, fraction_digits: usize) -> String {
+        let text = format!("{:.*}", fraction_digits, value.abs());
+        let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+        let mut result = String::new();
+        if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            result.push('-');
+        }
+        for (i, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - i;
+            if i > 0 && remaining % 3 == 0 && integer.len() >= self.min_grouped_len {
+                result.push(self.group);
+            }
+            result.push(digit);
+        }
+        if !fraction.is_empty() {
+            result.push(self.decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    fn format_fluent(&self, number: &FluentNumber) -> String {
+        let options = &number.options;
+        let digits = options.maximum_fraction_digits.or(options.minimum_fraction_digits);
+        // 没有指定小数位数时，整数不带小数，其余保留两位
+        let digits = digits.unwrap_or(if number.value.fract() == 0.0 { 0 } else { 2 });
+        self.format(number.value, digits)
+    }
+}
+
+impl Memoizable for NumberFormat {
+    type Args = ();
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+        Ok(Self::for_language(Language::from_tag(&lang.to_string()).unwrap_or(Language::English)))
+    }
+}
+
+// 系统语言，不在支持范围内时使用英文
+pub fn system_language() -> Language {
+    sys_locale::get_locale().and_then(|locale| Language::from_locale(&locale)).unwrap_or(Language::English)
+}
+
+impl Language {
+    // 解析 "zh_TW.UTF-8"、"de-DE"、"zh-Hant-HK" 这类系统区域设置
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let locale = locale.split(['.', '@']).next()?.replace('_', "-");
+        let mut parts = locale.split('-');
+        let language = parts.next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "zh" if parts.any(|part| matches!(part.to_ascii_uppercase().as_str(), "HANT" | "TW" | "HK" | "MO")) => {
+                Some(Language::TraditionalChinese)
+            }
+            "zh" => Some(Language::Chinese),
+            "en" => Some(Language::English),
+            "ja" => Some(Language::Japanese),
+            "ko" => Some(Language::Korean),
+            "de" => Some(Language::German),
+            "es" => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    // chrono 的区域设置，用于显示月份和星期名称
+    fn chrono_locale(&self) -> chrono::Locale {
+        match self {
+            Language::Chinese => chrono::Locale::zh_CN,
+            Language::English => chrono::Locale::en_US,
+            Language::TraditionalChinese => chrono::Locale::zh_TW,
+            Language::Japanese => chrono::Locale::ja_JP,
+            Language::Korean => chrono::Locale::ko_KR,
+            Language::German => chrono::Locale::de_DE,
+            Language::Spanish => chrono::Locale::es_ES,
+        }
+    }
+
+    pub fn format_number(&self, value: f64, fraction_digits: usize) -> String {
+        NumberFormat::for_language(*self).format(value, fraction_digits)
+    }
+
+    // 按翻译文件中 id 对应的 strftime 格式显示时间
+    pub fn format_time(&self, time: DateTime<Local>, id: &str) -> String {
+        let mut text = String::new();
+        if write!(text, "{}", time.format_localized(&self.tr(id), self.chrono_locale())).is_err() {
+            eprintln!("Invalid date format {} for {}", id, self.tag());
+            return time.format("%Y-%m-%d %H:%M").to_string();
+        }
+        text
+    }
+
+    pub fn format_date(&self, date: NaiveDate, id: &str) -> String {
+        let mut text = String::new();
+        if write!(text, "{}", date.format_localized(&self.tr(id), self.chrono_locale())).is_err() {
+            eprintln!("Invalid date format {} for {}", id, self.tag());
+            return date.format("%Y-%m-%d").to_string();
+        }
+        text
+    }
+
+    // 相对时间，例如 "3 min ago"
+    pub fn format_relative(&self, time: DateTime<Local>, now: DateTime<Local>) -> String {
+        let seconds = (now - time).num_seconds().max(0);
+        let (minutes, hours, days) = (seconds / 60, seconds / 3600, seconds / 86400);
+        if minutes < 1 {
+            self.tr("relative-just-now")
+        } else if hours < 1 {
+            self.tr_args("relative-minutes", &[("count", minutes.into())])
+        } else if days < 1 {
+            self.tr_args("relative-hours", &[("count", hours.into())])
+        } else {
+            self.tr_args("relative-days", &[("count", days.into())])
+        }
+    }
+
+    // 时间加相对时间，不是今天时带上日期
+    pub fn format_timestamp(&self, time: DateTime<Local>, now: DateTime<Local>) -> String {
+        let absolute = if time.date_naive() == now.date_naive() {
+            self.format_time(time, "time-of-day")
+        } else {
+            self.format_time(time, "date-time")
+        };
+        format!("{} ({})", absolute, self.format_relative(time, now))
+    }
+
+    pub fn format_forecast(&self, forecast: &Forecast) -> String {
+        let rate = self.tr_args("forecast-rate", &[("rate", forecast.requests_per_day.round().into())]);
+
+        let Some(exhausted_at) = forecast.exhausted_at else {
+            return self.tr_args("forecast-current-pace", &[("rate", rate.into())]);
+        };
+        let date = self.format_time(exhausted_at, "date-month-day");
+
+        // 重置当天用完时也要提示，只有提前至少一天时才显示天数
+        if forecast.overruns() {
+            return match forecast.days_before_reset() {
+                Some(days) if days >= 1 => {
+                    self.tr_args("forecast-runs-out-before-reset", &[("date", date.into()), ("days", days.into())])
+                }
+                _ => self.tr_args("forecast-runs-out", &[("date", date.into()), ("rate", rate.into())]),
+            };
+        }
+        match forecast.reset_at {
+            Some(_) => self.tr_args("forecast-lasts-until-reset", &[("rate", rate.into())]),
+            None => self.tr_args("forecast-runs-out", &[("date", date.into()), ("rate", rate.into())]),
+        }
+    }
+
+    pub fn format_weekday(&self, day: Weekday) -> String {
+        let id = match day {
+            Weekday::Mon => "weekday-mon",
+            Weekday::Tue => "weekday-tue",
+            Weekday::Wed => "weekday-wed",
+            Weekday::Thu => "weekday-thu",
+            Weekday::Fri => "weekday-fri",
+            Weekday::Sat => "weekday-sat",
+            Weekday::Sun => "weekday-sun",
+        };
+        self.tr(id)
+    }
+
+    pub fn format_day(&self, date: NaiveDate) -> String {
+        let weekday = self.format_weekday(date.weekday());
+        self.tr_args("date-day", &[("date", self.format_date(date, "date-short").into()), ("weekday", weekday.into())])
+    }
+
+    pub fn tr(&self, id: &str) -> String {
+        self.tr_args(id, &[])
+    }
+
+    pub fn tr_args(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+
+        // 当前语言缺少该条目时回退到英文
+        let bundles = bundles();
+        for lang in [*self, Language::English] {
+            let Some(bundle) = bundles.get(&lang) else {
+                continue;
+            };
+            if let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) {
+                let mut errors = Vec::new();
+                let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+                if !errors.is_empty() {
+                    eprintln!("Failed to format message {} for {}: {:?}", id, lang.tag(), errors);
+                }
+                return text.into_owned();
+            }
+        }
+        id.to_string()
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::Language;
+
+// 一个用量等级：使用率达到 threshold（百分比）后使用的颜色和名称
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UsageLevel {
+    pub threshold: f32,
+    // "#RRGGBB"
+    pub color: String,
+    #[serde(default)]
+    pub label: String,
+}
+
+// 只对某个模型或账户生效的等级，未设置的条件匹配任意值
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UsageLevelRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    pub levels: Vec<UsageLevel>,
+}
+
+// 内置的等级预设，Custom 使用 settings.json 中的 usage_levels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LevelPreset {
+    Standard,
+    Relaxed,
+    Strict,
+    ColorBlind,
+    Custom,
+}
+
+impl LevelPreset {
+    pub fn from_str(preset: &str) -> Self {
+        match preset {
+            "Relaxed" => LevelPreset::Relaxed,
+            "Strict" => LevelPreset::Strict,
+            "ColorBlind" => LevelPreset::ColorBlind,
+            "Custom" => LevelPreset::Custom,
+            _ => LevelPreset::Standard,
+        }
+    }
+
+    // 菜单中列出的内置预设
+    pub fn all() -> &'static [LevelPreset] {
+        &[LevelPreset::Standard, LevelPreset::Relaxed, LevelPreset::Strict, LevelPreset::ColorBlind]
+    }
+
+    // 预设的等级，名称使用当前语言；Custom 没有内置等级，按 Standard 处理
+    pub fn levels(&self, lang: Language) -> Vec<UsageLevel> {
+        let (thresholds, colors) = match self {
+            LevelPreset::Standard | LevelPreset::Custom => ([0.0, 50.0, 70.0, 90.0], STANDARD_COLORS),
+            LevelPreset::Relaxed => ([0.0, 70.0, 85.0, 95.0], STANDARD_COLORS),
+            LevelPreset::Strict => ([0.0, 30.0, 50.0, 75.0], STANDARD_COLORS),
+            LevelPreset::ColorBlind => ([0.0, 50.0, 70.0, 90.0], COLOR_BLIND_COLORS),
+        };
+        let labels = ["level-low", "level-moderate", "level-high", "level-critical"];
+
+        thresholds
+            .iter()
+            .zip(colors)
+            .zip(labels)
+            .map(|((&threshold, color), label)| UsageLevel { threshold, color: color.to_string(), label: lang.tr(label) })
+            .collect()
+    }
+}
+
+impl fmt::Display for LevelPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LevelPreset::Standard => write!(f, "Standard"),
+            LevelPreset::Relaxed => write!(f, "Relaxed"),
+            LevelPreset::Strict => write!(f, "Strict"),
+            LevelPreset::ColorBlind => write!(f, "ColorBlind"),
+            LevelPreset::Custom => write!(f, "Custom"),
+        }
+    }
+}
+
+// 绿、黄、橙、红
+const STANDARD_COLORS: [&str; 4] = ["#32C864", "#FAE61E", "#FA961E", "#E62828"];
+// Okabe-Ito 配色中的蓝、黄、橙、朱红，色盲用户也容易区分
+const COLOR_BLIND_COLORS: [&str; 4] = ["#0072B2", "#F0E442", "#E69F00", "#D55E00"];
+
+// 解析 "#RRGGBB" 格式的颜色
+fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+impl UsageLevel {
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        parse_color(&self.color).unwrap_or((128, 128, 128))
+    }
+}
+
+impl UsageLevelRule {
+    pub fn matches(&self, account: Option<&str>, model: &str) -> bool {
+        self.model.as_deref().is_none_or(|m| m == model)
+            && self.account.as_ref().is_none_or(|a| account.is_some_and(|account| a.eq_ignore_ascii_case(account)))
+    }
+}
+
+// 检查手工编辑的等级：阈值在 0-100 之间且递增，颜色为 #RRGGBB
+pub fn validate_levels(levels: &[UsageLevel]) -> Result<(), String> {
+    if levels.is_empty() {
+        return Err("Usage levels must not be empty".to_string());
+    }
+    for level in levels {
+        if !(0.0..=100.0).contains(&level.threshold) {
+            return Err(format!("Invalid usage level threshold: {}, expected 0-100", level.threshold));
+        }
+        if parse_color(&level.color).is_none() {
+            return Err(format!("Invalid usage level color: {}, expected #RRGGBB", level.color));
+        }
+    }
+    if levels.windows(2).any(|pair| pair[0].threshold >= pair[1].threshold) {
+        return Err("Usage level thresholds must be in increasing order".to_string());
+    }
+    Ok(())
+}
+
+// 按阈值从低到高排列的一组等级，至少包含一个等级
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsageLevels {
+    levels: Vec<UsageLevel>,
+}
+
+impl UsageLevels {
+    // levels 需要先经过 validate_levels 检查
+    pub fn new(levels: Vec<UsageLevel>) -> Self {
+        debug_assert!(!levels.is_empty());
+        Self { levels }
+    }
+
+    // 使用率所在的等级，低于第一个阈值时使用第一个等级
+    pub fn level_for(&self, percentage: f32) -> &UsageLevel {
+        self.levels.iter().rev().find(|l| percentage >= l.threshold).unwrap_or(&self.levels[0])
+    }
+
+    // 把 0..tiers 的分档按比例对应到等级上，最低档为最低等级，最高档为最高等级
+    pub fn level_for_tier(&self, tier: usize, tiers: usize) -> &UsageLevel {
+        let last = self.levels.len() - 1;
+        let index = match tiers {
+            0 | 1 => 0,
+            _ => (tier.min(tiers - 1) * last + (tiers - 1) / 2) / (tiers - 1),
+        };
+        &self.levels[index]
+    }
+
+    // 颜色发生变化的阈值，不包括最低等级的起点
+    pub fn thresholds(&self) -> Vec<f32> {
+        self.levels.iter().skip(1).map(|l| l.threshold).collect()
+    }
+
+    // 预计在重置前用完时提前显示的等级，即最高等级的前一级
+    pub fn warning(&self) -> &UsageLevel {
+        &self.levels[self.levels.len().saturating_sub(2)]
+    }
+}
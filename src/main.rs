@@ -1,12 +1,27 @@
+mod adaptive;
 mod api;
+mod breakdown;
+mod cli;
 mod config;
+mod events;
+mod export;
+mod forecast;
+mod history;
+mod i18n;
+mod levels;
+mod reload;
 mod settings;
 mod tray;
 mod utils;
+mod wake;
 mod menu;
+mod pace;
+mod policy;
+mod power;
+mod process;
+mod schedule;
+mod scheduler;
 
-use std::thread;
-use std::time::Duration;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -16,11 +31,26 @@ use tao::event_loop::ControlFlow;
 use tao::event_loop::EventLoopBuilder;
 use tray_icon::menu::MenuEvent;
 
+use crate::cli::Command;
 use crate::config::SharedState;
+use crate::scheduler::RefreshScheduler;
 use crate::menu::UserEvent;
 use crate::tray::TrayManager;
 
 fn main() -> Result<()> {
+    // 命令行子命令不需要启动托盘
+    let args = match cli::parse_args(std::env::args().skip(1))? {
+        Command::Export(args) => return cli::run_export(args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Command::Run(args) => args,
+    };
+
+    // 在读取设置之前确定设置文件路径和覆盖
+    settings::configure(args.config, args.overrides);
+
     // 初始化共享状态
     let state = SharedState::new();
 
@@ -31,25 +61,29 @@ fn main() -> Result<()> {
     // 设置事件处理器
     setup_event_handlers(&event_loop);
 
+    // 启动后台刷新线程，事件循环开始运行后才会进行第一次刷新
+    let shutdown_flag = Arc::new(AtomicBool::new(false));
+    let refresher = RefreshScheduler::start(&state, shutdown_flag.clone());
+    let starter = refresher.clone();
+
+    // 监视设置文件，外部编辑后立即生效
+    reload::watch_settings(&state, shutdown_flag.clone());
+
     // 创建托盘管理器
-    let mut tray_manager = TrayManager::new(state.clone());
+    let mut tray_manager = TrayManager::new(state.clone(), refresher);
     tray_manager.set_event_loop_proxy(event_loop_proxy.clone());
 
     // 初始化托盘图标（不等待数据）
     tray_manager.initialize_without_data();
 
-    // 启动后台更新线程
-    let shutdown_flag = Arc::new(AtomicBool::new(false));
-    start_background_updater(&event_loop, &state, shutdown_flag.clone());
-
     // 运行事件循环
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
 
         match event {
             Event::NewEvents(tao::event::StartCause::Init) => {
-                // 发送初始数据更新事件
-                let _ = event_loop_proxy.send_event(UserEvent::UpdateData);
+                // 托盘已经订阅了状态事件，开始第一次刷新
+                starter.start();
 
                 #[cfg(target_os = "macos")]
                 {
@@ -79,36 +113,3 @@ fn setup_event_handlers(event_loop: &tao::event_loop::EventLoop<UserEvent>) {
         let _ = proxy.send_event(UserEvent::MenuEvent(event));
     }));
 }
-
-fn start_background_updater(
-    event_loop: &tao::event_loop::EventLoop<UserEvent>, 
-    state: &SharedState,
-    shutdown_flag: Arc<AtomicBool>
-) {
-    let state_clone = state.clone();
-    let proxy = event_loop.create_proxy();
-
-    thread::spawn(move || {
-        while !shutdown_flag.load(Ordering::Relaxed) {
-            // 先发送更新数据事件
-            if proxy.send_event(UserEvent::UpdateData).is_err() {
-                // 如果发送失败，说明事件循环已经关闭，退出线程
-                break;
-            }
-
-            // 获取当前刷新间隔
-            let sleep_duration = state_clone.get_refresh_interval().as_secs();
-            
-            // 分段睡眠，以便能够及时响应关闭信号
-            let sleep_chunks = (sleep_duration / 5).max(1); // 最多分成5段
-            let chunk_duration = sleep_duration / sleep_chunks;
-            
-            for _ in 0..sleep_chunks {
-                if shutdown_flag.load(Ordering::Relaxed) {
-                    return;
-                }
-                thread::sleep(Duration::from_secs(chunk_duration));
-            }
-        }
-    });
-}
@@ -1,9 +1,19 @@
 use std::collections::HashMap;
-use tray_icon::menu::{Menu, MenuItem, MenuEvent};
+use chrono::Local;
+use tray_icon::menu::{Menu, MenuItem, MenuEvent, PredefinedMenuItem, Submenu};
 use tao::event_loop::ControlFlow;
 use tao::event_loop::EventLoopProxy;
 
-use crate::config::{Language, RefreshInterval, UsageData, SharedState};
+use crate::breakdown::{UsageBreakdown, text_bar};
+use crate::export::{ExportFormat, ExportRange, export_range};
+use crate::config::{AccountDisplay, ColorMode, IconStyle, Language, LanguagePreference, PauseState, RefreshInterval, UsageData, SharedState};
+use crate::forecast::Forecast;
+use crate::i18n::fixed;
+use crate::levels::{LevelPreset, UsageLevel};
+use crate::pace::Pace;
+use crate::policy::{clamp_refresh_interval, has_interval_bounds, is_locked, rejected_policy_fields};
+use crate::schedule::QuietSchedule;
+use crate::settings::{SettingsWarning, get_config_path, is_overridden};
 
 // 用户事件枚举
 pub enum UserEvent {
@@ -18,8 +28,17 @@ pub enum MenuAction {
     Refresh,
     OpenSettings,
     Quit,
-    SetLanguage(Language),
+    SetLanguage(LanguagePreference),
     SetInterval(RefreshInterval),
+    SetColorMode(ColorMode),
+    ToggleSkipWeekends,
+    Export(ExportRange, ExportFormat),
+    SetIconStyle(IconStyle),
+    SetLevelPreset(LevelPreset),
+    PauseFor1h,
+    PauseUntilTomorrow,
+    PauseUntilResumed,
+    Resume,
 }
 
 impl MenuAction {
@@ -28,16 +47,36 @@ impl MenuAction {
             "refresh" => Some(MenuAction::Refresh),
             "open_settings" => Some(MenuAction::OpenSettings),
             "quit" => Some(MenuAction::Quit),
-            "lang_chinese" => Some(MenuAction::SetLanguage(Language::Chinese)),
-            "lang_english" => Some(MenuAction::SetLanguage(Language::English)),
-            s if s.starts_with("interval_") => match s.strip_prefix("interval_")? {
-                "Min1" => Some(MenuAction::SetInterval(RefreshInterval::Min1)),
-                "Min5" => Some(MenuAction::SetInterval(RefreshInterval::Min5)),
-                "Min10" => Some(MenuAction::SetInterval(RefreshInterval::Min10)),
-                "Min30" => Some(MenuAction::SetInterval(RefreshInterval::Min30)),
-                "Hour1" => Some(MenuAction::SetInterval(RefreshInterval::Hour1)),
-                _ => None,
-            },
+            "color_absolute" => Some(MenuAction::SetColorMode(ColorMode::Absolute)),
+            "color_pace" => Some(MenuAction::SetColorMode(ColorMode::Pace)),
+            "toggle_skip_weekends" => Some(MenuAction::ToggleSkipWeekends),
+            "pause_1h" => Some(MenuAction::PauseFor1h),
+            "pause_tomorrow" => Some(MenuAction::PauseUntilTomorrow),
+            "pause_indefinitely" => Some(MenuAction::PauseUntilResumed),
+            "resume" => Some(MenuAction::Resume),
+            "style_disc" => Some(MenuAction::SetIconStyle(IconStyle::Disc)),
+            "style_sparkline" => Some(MenuAction::SetIconStyle(IconStyle::Sparkline)),
+            "style_bars" => Some(MenuAction::SetIconStyle(IconStyle::Bars)),
+            s if s.starts_with("levels_") => Some(MenuAction::SetLevelPreset(LevelPreset::from_str(s.strip_prefix("levels_")?))),
+            s if s.starts_with("export_") => {
+                let (range, format) = s.strip_prefix("export_")?.split_once('_')?;
+                let range = match range {
+                    "ThisMonth" => ExportRange::ThisMonth,
+                    "LastMonth" => ExportRange::LastMonth,
+                    "Last30Days" => ExportRange::Last30Days,
+                    _ => return None,
+                };
+                Some(MenuAction::Export(range, ExportFormat::from_str(format)?))
+            }
+            "lang_system" => Some(MenuAction::SetLanguage(LanguagePreference::System)),
+            s if s.starts_with("lang_") => {
+                let lang = Language::from_tag(s.strip_prefix("lang_")?)?;
+                Some(MenuAction::SetLanguage(LanguagePreference::Fixed(lang)))
+            }
+            s if s.starts_with("interval_") => {
+                let secs = s.strip_prefix("interval_")?.parse().ok()?;
+                Some(MenuAction::SetInterval(RefreshInterval::from_secs(secs)))
+            }
             _ => None,
         }
     }
@@ -57,73 +96,65 @@ impl MenuAction {
             }
             MenuAction::SetLanguage(lang) => {
                 state.set_language(*lang);
-                if let Some(proxy) = event_loop_proxy {
-                    let _ = proxy.send_event(UserEvent::UpdateTrayIcon);
-                }
             }
             MenuAction::SetInterval(interval) => {
                 state.set_refresh_interval(*interval);
+            }
+            MenuAction::SetColorMode(mode) => {
+                state.set_color_mode(*mode);
+            }
+            MenuAction::PauseFor1h => {
+                state.set_pause(Some(PauseState::Until(Local::now() + chrono::Duration::hours(1))));
+            }
+            MenuAction::PauseUntilTomorrow => {
+                let tomorrow = (Local::now().date_naive() + chrono::Duration::days(1))
+                    .and_hms_opt(0, 0, 0)
+                    .and_then(|t| t.and_local_timezone(Local).earliest());
+                if let Some(tomorrow) = tomorrow {
+                    state.set_pause(Some(PauseState::Until(tomorrow)));
+                }
+            }
+            MenuAction::PauseUntilResumed => {
+                state.set_pause(Some(PauseState::Indefinitely));
+            }
+            MenuAction::Resume => {
+                state.set_pause(None);
+                // 恢复后立即刷新一次
                 if let Some(proxy) = event_loop_proxy {
-                    let _ = proxy.send_event(UserEvent::UpdateTrayIcon);
+                    let _ = proxy.send_event(UserEvent::UpdateData);
                 }
             }
+            MenuAction::Export(range, format) => {
+                let Some(history) = state.history() else {
+                    eprintln!("Usage history is not available, nothing to export");
+                    return;
+                };
+                match export_range(&history, *range, *format, state.get_language()) {
+                    Ok(path) => {
+                        // 导出完成后打开所在文件夹
+                        if let Some(dir) = path.parent() {
+                            let _ = open::that(dir);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to export usage history: {}", e),
+                }
+            }
+            MenuAction::SetIconStyle(style) => {
+                state.set_icon_style(*style);
+            }
+            MenuAction::SetLevelPreset(preset) => {
+                state.set_level_preset(*preset);
+            }
+            MenuAction::ToggleSkipWeekends => {
+                state.set_skip_weekends(!state.get_skip_weekends());
+            }
         }
     }
 }
 
-// 菜单文本
-#[derive(Clone, Copy)]
-pub struct MenuTexts {
-    pub title: &'static str,
-    pub refresh: &'static str,
-    pub settings: &'static str,
-    pub quit: &'static str,
-    pub language: &'static str,
-    pub refresh_interval: &'static str,
-    pub used: &'static str,
-    pub remaining: &'static str,
-    pub usage_rate: &'static str,
-    pub account: &'static str,
-    pub last_update: &'static str,
-    pub requests: &'static str,
-    pub options: &'static str,
-}
-
-impl Language {
-    pub fn get_menu_texts(&self) -> MenuTexts {
-        match self {
-            Language::Chinese => MenuTexts {
-                title: "🤖 Cursor GPT-4 用量",
-                language: "----- 🇺🇳 语言 -----",
-                refresh_interval: "----- ⏳ 刷新间隔 -----",
-                used: "已用",
-                remaining: "剩余",
-                usage_rate: "使用率",
-                account: "账户",
-                last_update: "最后更新",
-                requests: "次请求",
-                options: "----- ⚙️ 选项 -----",
-                refresh: "刷新数据",
-                settings: "打开Cursor设置",
-                quit: "退出",
-            },
-            Language::English => MenuTexts {
-                title: "🤖 Cursor GPT-4 Usage",
-                language: "-----🇺🇳 Language -----",
-                refresh_interval: "----- ⏳ Refresh Interval -----",
-                used: "Used",
-                remaining: "Remaining",
-                usage_rate: "Usage",
-                account: "Account",
-                last_update: "Last updated",
-                requests: "requests",
-                options: "----- ⚙️ Options -----",
-                refresh: "Refresh Data",
-                settings: "Open Cursor Settings",
-                quit: "Exit",
-            },
-        }
-    }
+// 既没有被策略锁定，也没有被环境变量或命令行覆盖的字段才能在菜单中修改
+fn is_editable(field: &str) -> bool {
+    !is_locked(field) && !is_overridden(field)
 }
 
 // 菜单构建器
@@ -131,8 +162,23 @@ pub struct MenuBuilder {
     menu: Menu,
     actions: HashMap<String, String>,
     language: Language,
+    language_preference: LanguagePreference,
     refresh_interval: RefreshInterval,
     usage_data: UsageData,
+    forecast: Option<Forecast>,
+    pace: Option<Pace>,
+    breakdown: Option<UsageBreakdown>,
+    color_mode: ColorMode,
+    skip_weekends: bool,
+    icon_style: IconStyle,
+    level_preset: LevelPreset,
+    has_custom_levels: bool,
+    usage_level: Option<UsageLevel>,
+    account_display: AccountDisplay,
+    stale: bool,
+    quiet_schedule: Option<QuietSchedule>,
+    pause: Option<PauseState>,
+    settings_warning: Option<SettingsWarning>,
 }
 
 impl MenuBuilder {
@@ -141,15 +187,91 @@ impl MenuBuilder {
             menu: Menu::new(),
             actions: HashMap::new(),
             language,
+            language_preference: LanguagePreference::Fixed(language),
             refresh_interval,
             usage_data,
+            forecast: None,
+            pace: None,
+            breakdown: None,
+            color_mode: ColorMode::Absolute,
+            skip_weekends: false,
+            icon_style: IconStyle::Disc,
+            level_preset: LevelPreset::Standard,
+            has_custom_levels: false,
+            usage_level: None,
+            account_display: AccountDisplay::Full,
+            stale: false,
+            quiet_schedule: None,
+            pause: None,
+            settings_warning: None,
         }
     }
 
+    pub fn with_settings_warning(mut self, warning: Option<SettingsWarning>) -> Self {
+        self.settings_warning = warning;
+        self
+    }
+
+    pub fn with_language_preference(mut self, preference: LanguagePreference) -> Self {
+        self.language_preference = preference;
+        self
+    }
+
+    pub fn with_pause(mut self, pause: Option<PauseState>) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    pub fn with_quiet_schedule(mut self, schedule: Option<QuietSchedule>) -> Self {
+        self.quiet_schedule = schedule;
+        self
+    }
+
+    pub fn with_stale(mut self, stale: bool) -> Self {
+        self.stale = stale;
+        self
+    }
+
+    pub fn with_icon_style(mut self, icon_style: IconStyle) -> Self {
+        self.icon_style = icon_style;
+        self
+    }
+
+    pub fn with_account_display(mut self, display: AccountDisplay) -> Self {
+        self.account_display = display;
+        self
+    }
+
+    pub fn with_levels(mut self, preset: LevelPreset, has_custom_levels: bool, usage_level: UsageLevel) -> Self {
+        self.level_preset = preset;
+        self.has_custom_levels = has_custom_levels;
+        self.usage_level = Some(usage_level);
+        self
+    }
+
+    pub fn with_breakdown(mut self, breakdown: Option<UsageBreakdown>) -> Self {
+        self.breakdown = breakdown;
+        self
+    }
+
+    pub fn with_pace(mut self, color_mode: ColorMode, skip_weekends: bool, pace: Option<Pace>) -> Self {
+        self.color_mode = color_mode;
+        self.skip_weekends = skip_weekends;
+        self.pace = pace;
+        self
+    }
+
+    pub fn with_forecast(mut self, forecast: Option<Forecast>) -> Self {
+        self.forecast = forecast;
+        self
+    }
+
     pub fn build(mut self) -> (Menu, HashMap<String, String>) {
         self.add_title()
             .add_usage_info()
+            .add_breakdown_menu()
             .add_refresh_interval_menu()
+            .add_icon_color_menu()
             .add_language_menu()
             .add_options_menu();
 
@@ -157,92 +279,324 @@ impl MenuBuilder {
     }
 
     fn add_title(&mut self) -> &mut Self {
-        let texts = self.language.get_menu_texts();
-        self.menu.append(&MenuItem::new(texts.title, false, None)).unwrap();
+        let lang = self.language;
+        self.menu.append(&MenuItem::new(lang.tr("menu-title"), false, None)).unwrap();
+
+        // 设置文件可能是 settings.json、settings.toml 或 --config 指定的文件
+        let file = get_config_path()
+            .ok()
+            .and_then(|path| path.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "settings.json".to_string());
+        let warning = match &self.settings_warning {
+            Some(SettingsWarning::Corrupted { backup }) => Some(lang.tr_args(
+                "settings-corrupted",
+                &[("file", file.into()), ("path", backup.display().to_string().into())],
+            )),
+            Some(SettingsWarning::InvalidFields(fields)) => {
+                Some(lang.tr_args("settings-invalid-fields", &[("fields", fields.join(", ").into())]))
+            }
+            Some(SettingsWarning::Unreadable(error)) => {
+                Some(lang.tr_args("settings-unreadable", &[("file", file.clone().into()), ("error", error.clone().into())]))
+            }
+            Some(SettingsWarning::ReloadFailed(error)) => {
+                Some(lang.tr_args("settings-reload-failed", &[("file", file.into()), ("error", error.clone().into())]))
+            }
+            None => None,
+        };
+        // 策略文件中无效的字段需要告诉用户，否则管理员以为设置已经生效
+        let rejected = rejected_policy_fields();
+        let policy_warning = (!rejected.is_empty())
+            .then(|| lang.tr_args("policy-invalid-fields", &[("fields", rejected.join(", ").into())]));
+
+        for warning in warning.into_iter().chain(policy_warning) {
+            self.menu.append(&MenuItem::new(warning, false, None)).unwrap();
+        }
         self
     }
 
+    // 分组中有被策略锁定或被环境变量、命令行覆盖的设置时，在标题下注明，返回是否不可修改
+    fn add_lock_note(&mut self, fields: &[&str]) -> bool {
+        let note = if fields.iter().any(|field| is_locked(field)) {
+            "managed-by-organization"
+        } else if fields.iter().any(|field| is_overridden(field)) {
+            "overridden-for-session"
+        } else {
+            return false;
+        };
+        self.menu.append(&MenuItem::new(self.language.tr(note), false, None)).unwrap();
+        true
+    }
+
     fn add_usage_info(&mut self) -> &mut Self {
-        let texts = self.language.get_menu_texts();
+        let lang = self.language;
 
-        if let Some(error) = &self.usage_data.error {
-            self.menu.append(&MenuItem::new(format!("❌ Error: {}", error), false, None)).unwrap();
+        match self.pause {
+            Some(PauseState::Until(until)) => {
+                let text = lang.tr_args("paused-until", &[("time", lang.format_time(until, "date-time").into())]);
+                self.menu.append(&MenuItem::new(text, false, None)).unwrap();
+            }
+            Some(PauseState::Indefinitely) => {
+                self.menu.append(&MenuItem::new(lang.tr("paused"), false, None)).unwrap();
+            }
+            None => {}
+        }
+
+        if let Some(error) = &self.usage_data.error
+            && !self.usage_data.has_snapshot()
+        {
+            let error_text = lang.tr_args("usage-error", &[("error", error.describe(lang).into())]);
+            self.menu.append(&MenuItem::new(error_text, false, None)).unwrap();
         } else {
-            let used_text = format!("{}: {}/{} {}", texts.used, self.usage_data.used, self.usage_data.total, texts.requests);
-            let remaining_text = format!("{}: {} {}", texts.remaining, self.usage_data.total - self.usage_data.used, texts.requests);
-            let percentage_text = format!("{}: {:.1}%", texts.usage_rate, self.usage_data.percentage);
+            let data = &self.usage_data;
+            let used_text = lang.tr_args("usage-used", &[("used", data.used.into()), ("total", data.total.into())]);
+            let remaining_text = lang.tr_args("usage-remaining", &[("count", (data.total - data.used).into())]);
+            let percentage = fixed(data.percentage as f64, 1);
+            let percentage_text = match &self.usage_level {
+                Some(level) if !level.label.is_empty() => {
+                    lang.tr_args("usage-rate-level", &[("percentage", percentage), ("level", level.label.as_str().into())])
+                }
+                _ => lang.tr_args("usage-rate", &[("percentage", percentage)]),
+            };
 
             self.menu.append(&MenuItem::new(used_text, false, None)).unwrap();
             self.menu.append(&MenuItem::new(remaining_text, false, None)).unwrap();
             self.menu.append(&MenuItem::new(percentage_text, false, None)).unwrap();
 
-            if let Some(email) = &self.usage_data.email {
-                let email_text = format!("{}: {}", texts.account, email);
+            if let Some(pace) = &self.pace {
+                let id = if pace.ahead > 0.0 { "usage-pace-ahead" } else { "usage-pace-behind" };
+                let pace_text = lang.tr_args(id, &[("percentage", fixed(pace.ahead.abs() as f64, 1))]);
+                self.menu.append(&MenuItem::new(pace_text, false, None)).unwrap();
+            }
+
+            if let Some(forecast) = &self.forecast {
+                self.menu.append(&MenuItem::new(lang.format_forecast(forecast), false, None)).unwrap();
+            }
+
+            if let Some(email) = data.email.as_deref().and_then(|e| self.account_display.apply(e)) {
+                let email_text = lang.tr_args("usage-account", &[("email", email.into())]);
                 self.menu.append(&MenuItem::new(email_text, false, None)).unwrap();
             }
 
-            let update_time_text = format!("{}: {}", texts.last_update, self.usage_data.last_update);
+            let now = Local::now();
+            let last_update = match data.last_success {
+                Some(time) => lang.format_timestamp(time, now),
+                None => lang.tr("usage-never"),
+            };
+            let update_time_text = lang.tr_args("usage-last-update", &[("time", last_update.into())]);
             self.menu.append(&MenuItem::new(update_time_text, false, None)).unwrap();
+
+            if let Some(time) = data.last_success
+                && self.stale
+            {
+                let stale_text = lang.tr_args("usage-stale", &[("time", lang.format_relative(time, now).into())]);
+                self.menu.append(&MenuItem::new(stale_text, false, None)).unwrap();
+            }
+
+            // 刷新失败时仍展示上次成功的数据，并给出失败原因和时间
+            if let Some(error) = &data.error {
+                let failed_at = match data.last_attempt {
+                    Some(time) => format!(" ({})", lang.format_timestamp(time, now)),
+                    None => String::new(),
+                };
+                let error_text = lang.tr_args("usage-refresh-failed", &[("error", error.describe(lang).into())]);
+                self.menu.append(&MenuItem::new(format!("{}{}", error_text, failed_at), false, None)).unwrap();
+            }
+        }
+        self
+    }
+
+    fn add_breakdown_menu(&mut self) -> &mut Self {
+        let Some(breakdown) = &self.breakdown else {
+            return self;
+        };
+        let lang = self.language;
+        let submenu = Submenu::new(lang.tr("breakdown"), true);
+
+        for (id, requests) in [
+            ("breakdown-today", breakdown.today),
+            ("breakdown-yesterday", breakdown.yesterday),
+            ("breakdown-this-week", breakdown.this_week),
+        ] {
+            submenu.append(&MenuItem::new(lang.tr_args(id, &[("count", requests.into())]), false, None)).unwrap();
+        }
+        submenu.append(&PredefinedMenuItem::separator()).unwrap();
+
+        // 最新的一天放在最上面
+        let max = breakdown.max_daily();
+        for day in breakdown.days.iter().rev() {
+            let requests = lang.format_number(day.requests as f64, 0);
+            let text = format!("{}  {:>5}  {}", lang.format_day(day.date), requests, text_bar(day.requests, max, 12));
+            submenu.append(&MenuItem::new(text, false, None)).unwrap();
         }
+
+        self.menu.append(&submenu).unwrap();
         self
     }
 
     fn add_refresh_interval_menu(&mut self) -> &mut Self {
-        let texts = self.language.get_menu_texts();
-        self.menu.append(&MenuItem::new(texts.refresh_interval, false, None)).unwrap();
+        let lang = self.language;
+        self.menu.append(&MenuItem::new(lang.tr("menu-refresh-interval"), false, None)).unwrap();
+        let locked = self.add_lock_note(&["refresh_interval"]);
+        // 策略只限定了范围时，范围外的间隔不能选择
+        let bounded = !locked && has_interval_bounds();
+        if bounded {
+            self.menu.append(&MenuItem::new(lang.tr("managed-by-organization"), false, None)).unwrap();
+        }
+
+        // 自定义间隔只有在正在使用时才显示
+        let mut intervals = RefreshInterval::all().to_vec();
+        if let RefreshInterval::Custom(_) = self.refresh_interval {
+            intervals.push(self.refresh_interval);
+        }
 
-        for &interval in RefreshInterval::all() {
+        for interval in intervals {
             let check_mark = if interval == self.refresh_interval { "✓ " } else { "    " };
-            let text = format!("{}{}", check_mark, interval.to_string(self.language));
-            let item = MenuItem::new(text, true, None);
+            let text = format!("{}{}", check_mark, interval.to_string(lang));
+            let secs = interval.as_secs();
+            let allowed = interval == RefreshInterval::Adaptive || clamp_refresh_interval(secs) == secs;
+            let item = MenuItem::new(text, !locked && allowed, None);
             let id = item.id().0.to_string();
-            self.actions.insert(id.clone(), format!("interval_{:?}", interval));
+            self.actions.insert(id.clone(), format!("interval_{}", secs));
+            self.menu.append(&item).unwrap();
+        }
+
+        if let Some(schedule) = &self.quiet_schedule {
+            let mut text = schedule.describe(lang);
+            if schedule.is_quiet(Local::now()) {
+                text.push_str(&lang.tr("quiet-active"));
+            }
+            self.menu.append(&MenuItem::new(text, false, None)).unwrap();
+        }
+        self
+    }
+
+    fn add_icon_color_menu(&mut self) -> &mut Self {
+        let lang = self.language;
+        self.menu.append(&MenuItem::new(lang.tr("menu-icon"), false, None)).unwrap();
+        self.add_lock_note(&["color_mode", "pace_skip_weekends", "icon_style", "level_preset"]);
+
+        for (mode, label, action) in [
+            (ColorMode::Absolute, "color-absolute", "color_absolute"),
+            (ColorMode::Pace, "color-pace", "color_pace"),
+        ] {
+            let check_mark = if mode == self.color_mode { "✓ " } else { "    " };
+            let item = MenuItem::new(format!("{}{}", check_mark, lang.tr(label)), is_editable("color_mode"), None);
+            self.actions.insert(item.id().0.to_string(), action.to_string());
+            self.menu.append(&item).unwrap();
+        }
+
+        let check_mark = if self.skip_weekends { "✓ " } else { "    " };
+        let skip_item = MenuItem::new(format!("{}{}", check_mark, lang.tr("skip-weekends")), is_editable("pace_skip_weekends"), None);
+        self.actions.insert(skip_item.id().0.to_string(), "toggle_skip_weekends".to_string());
+        self.menu.append(&skip_item).unwrap();
+
+        for &style in IconStyle::all() {
+            let (label, action) = match style {
+                IconStyle::Disc => ("style-disc", "style_disc"),
+                IconStyle::Sparkline => ("style-sparkline", "style_sparkline"),
+                IconStyle::Bars => ("style-bars", "style_bars"),
+            };
+            let check_mark = if style == self.icon_style { "✓ " } else { "    " };
+            let item = MenuItem::new(format!("{}{}", check_mark, lang.tr(label)), is_editable("icon_style"), None);
+            self.actions.insert(item.id().0.to_string(), action.to_string());
+            self.menu.append(&item).unwrap();
+        }
+
+        // 自定义等级只有在 settings.json 中定义后才显示
+        let mut presets = LevelPreset::all().to_vec();
+        if self.has_custom_levels {
+            presets.push(LevelPreset::Custom);
+        }
+        for preset in presets {
+            let label = match preset {
+                LevelPreset::Standard => "levels-standard",
+                LevelPreset::Relaxed => "levels-relaxed",
+                LevelPreset::Strict => "levels-strict",
+                LevelPreset::ColorBlind => "levels-color-blind",
+                LevelPreset::Custom => "levels-custom",
+            };
+            let check_mark = if preset == self.level_preset { "✓ " } else { "    " };
+            let item = MenuItem::new(format!("{}{}", check_mark, lang.tr(label)), is_editable("level_preset"), None);
+            self.actions.insert(item.id().0.to_string(), format!("levels_{}", preset));
             self.menu.append(&item).unwrap();
         }
         self
     }
 
     fn add_language_menu(&mut self) -> &mut Self {
-        let texts = self.language.get_menu_texts();
-        self.menu.append(&MenuItem::new(texts.language, false, None)).unwrap();
-
-        // 中文选项
-        let chinese_item = MenuItem::new(
-            format!("{}中文", if self.language == Language::Chinese { "✓ " } else { "    " }),
-            true,
-            None,
-        );
-        let chinese_id = chinese_item.id().0.to_string();
-        self.actions.insert(chinese_id, "lang_chinese".to_string());
-        self.menu.append(&chinese_item).unwrap();
-
-        // 英文选项
-        let english_item = MenuItem::new(
-            format!("{}English", if self.language == Language::English { "✓ " } else { "    " }),
-            true,
-            None,
-        );
-        let english_id = english_item.id().0.to_string();
-        self.actions.insert(english_id, "lang_english".to_string());
-        self.menu.append(&english_item).unwrap();
+        self.menu.append(&MenuItem::new(self.language.tr("menu-language"), false, None)).unwrap();
+        let locked = self.add_lock_note(&["language"]);
+
+        let check_mark = if self.language_preference == LanguagePreference::System { "✓ " } else { "    " };
+        let system_item = MenuItem::new(format!("{}{}", check_mark, self.language.tr("language-system")), !locked, None);
+        self.actions.insert(system_item.id().0.to_string(), "lang_system".to_string());
+        self.menu.append(&system_item).unwrap();
+
+        // 每种语言都用自己的语言显示名称
+        for &lang in Language::all() {
+            let check_mark = if self.language_preference == LanguagePreference::Fixed(lang) { "✓ " } else { "    " };
+            let item = MenuItem::new(format!("{}{}", check_mark, lang.tr("language-name")), !locked, None);
+            self.actions.insert(item.id().0.to_string(), format!("lang_{}", lang.tag()));
+            self.menu.append(&item).unwrap();
+        }
         self
     }
 
     fn add_options_menu(&mut self) -> &mut Self {
-        let texts = self.language.get_menu_texts();
-        self.menu.append(&MenuItem::new(texts.options, false, None)).unwrap();
+        let lang = self.language;
+        self.menu.append(&MenuItem::new(lang.tr("menu-options"), false, None)).unwrap();
+        let pause_locked = self.add_lock_note(&["pause"]);
 
-        let refresh_item = MenuItem::new(texts.refresh, true, None);
+        let refresh_item = MenuItem::new(lang.tr("refresh"), true, None);
         let refresh_id = refresh_item.id().0.to_string();
         self.actions.insert(refresh_id, "refresh".to_string());
         self.menu.append(&refresh_item).unwrap();
 
-        let settings_item = MenuItem::new(texts.settings, true, None);
+        if self.pause.is_some() {
+            let resume_item = MenuItem::new(lang.tr("resume"), !pause_locked, None);
+            self.actions.insert(resume_item.id().0.to_string(), "resume".to_string());
+            self.menu.append(&resume_item).unwrap();
+        } else {
+            let pause_menu = Submenu::new(lang.tr("pause"), !pause_locked);
+            for (label, action) in [
+                ("pause-1h", "pause_1h"),
+                ("pause-tomorrow", "pause_tomorrow"),
+                ("pause-indefinitely", "pause_indefinitely"),
+            ] {
+                let item = MenuItem::new(lang.tr(label), true, None);
+                self.actions.insert(item.id().0.to_string(), action.to_string());
+                pause_menu.append(&item).unwrap();
+            }
+            self.menu.append(&pause_menu).unwrap();
+        }
+
+        let export_menu = Submenu::new(lang.tr("export"), true);
+        for &range in ExportRange::all() {
+            let label = match range {
+                ExportRange::ThisMonth => lang.tr("export-this-month"),
+                ExportRange::LastMonth => lang.tr("export-last-month"),
+                ExportRange::Last30Days => lang.tr("export-last-30-days"),
+            };
+            let formats = [
+                (ExportFormat::Csv, "CSV".to_string()),
+                (ExportFormat::JsonLines, "JSON Lines".to_string()),
+                (ExportFormat::Text, lang.tr("export-format-text")),
+            ];
+            for (format, name) in formats {
+                let text = lang.tr_args("export-item", &[("range", label.as_str().into()), ("format", name.as_str().into())]);
+                let item = MenuItem::new(text, true, None);
+                self.actions.insert(item.id().0.to_string(), format!("export_{:?}_{}", range, format.extension()));
+                export_menu.append(&item).unwrap();
+            }
+        }
+        self.menu.append(&export_menu).unwrap();
+
+        let settings_item = MenuItem::new(lang.tr("settings"), true, None);
         let settings_id = settings_item.id().0.to_string();
         self.actions.insert(settings_id, "open_settings".to_string());
         self.menu.append(&settings_item).unwrap();
 
-        let quit_item = MenuItem::new(texts.quit, true, None);
+        let quit_item = MenuItem::new(lang.tr("quit"), true, None);
         let quit_id = quit_item.id().0.to_string();
         self.actions.insert(quit_id, "quit".to_string());
         self.menu.append(&quit_item).unwrap();
@@ -0,0 +1,72 @@
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::Local;
+use chrono::Weekday;
+
+use crate::config::UsageData;
+
+// 节奏模式的分档数，按比例对应到当前的用量等级上
+pub const PACE_TIERS: usize = 4;
+
+// 与理想线性消耗速度的比较结果
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pace {
+    // 按理想速度此时应当用掉的百分比
+    pub expected_percentage: f32,
+    // 实际使用率超出理想值的百分点，负数表示落后于节奏
+    pub ahead: f32,
+}
+
+impl Pace {
+    pub fn compute(data: &UsageData, now: DateTime<Local>, skip_weekends: bool) -> Option<Self> {
+        if !data.has_snapshot() || data.total <= 0 {
+            return None;
+        }
+        let (start, end) = data.billing_cycle()?;
+
+        let total = active_seconds(start, end, skip_weekends);
+        if total <= 0 {
+            return None;
+        }
+        let elapsed = active_seconds(start, now.clamp(start, end), skip_weekends);
+        let expected_percentage = (elapsed as f64 / total as f64 * 100.0) as f32;
+
+        Some(Self { expected_percentage, ahead: data.percentage - expected_percentage })
+    }
+
+    // 超前程度分档：0 为落后或持平，PACE_TIERS - 1 为超前 25 个百分点以上
+    pub fn tier(&self) -> usize {
+        if self.ahead >= 25.0 {
+            3
+        } else if self.ahead >= 10.0 {
+            2
+        } else if self.ahead > 0.0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+// [from, to) 之间计入节奏的秒数，可选跳过周末
+fn active_seconds(from: DateTime<Local>, to: DateTime<Local>, skip_weekends: bool) -> i64 {
+    if !skip_weekends {
+        return (to - from).num_seconds();
+    }
+
+    let mut seconds = 0;
+    let mut cursor = from;
+    while cursor < to {
+        let next_day = (cursor.date_naive() + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+            .unwrap_or(cursor + Duration::days(1));
+        let segment_end = next_day.min(to);
+        if !matches!(cursor.weekday(), Weekday::Sat | Weekday::Sun) {
+            seconds += (segment_end - cursor).num_seconds();
+        }
+        cursor = segment_end;
+    }
+    seconds
+}
@@ -0,0 +1,172 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::settings::split_valid_fields;
+
+// 策略文件只在启动时读取一次，修改后需要重启
+static POLICY: OnceLock<Policy> = OnceLock::new();
+
+// 限定刷新间隔范围的字段，不是设置字段
+const MIN_INTERVAL_FIELD: &str = "min_refresh_interval_secs";
+const MAX_INTERVAL_FIELD: &str = "max_refresh_interval_secs";
+
+struct Policy {
+    // 实际生效的字段
+    fields: Map<String, Value>,
+    // 取值无效而被忽略的字段
+    rejected: Vec<String>,
+    min_interval_secs: Option<u64>,
+    max_interval_secs: Option<u64>,
+}
+
+// 系统级策略文件的位置，普通用户通常没有写权限
+fn policy_path() -> Option<PathBuf> {
+    #[cfg(target_os = "linux")]
+    {
+        Some(PathBuf::from("/etc/cursorbar/policy.toml"))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Some(PathBuf::from("/Library/Application Support/CursorBar/policy.toml"))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var_os("ProgramData").map(|dir| PathBuf::from(dir).join("CursorBar").join("policy.toml"))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        None
+    }
+}
+
+fn read() -> Map<String, Value> {
+    let Some(path) = policy_path() else {
+        return Map::new();
+    };
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Map::new(),
+        Err(e) => {
+            eprintln!("Failed to read policy {}: {}", path.display(), e);
+            return Map::new();
+        }
+    };
+    match toml::from_str(&content) {
+        Ok(fields) => fields,
+        Err(e) => {
+            eprintln!("Failed to parse policy {}: {}", path.display(), e);
+            Map::new()
+        }
+    }
+}
+
+fn load() -> Policy {
+    Policy::from_fields(read())
+}
+
+impl Policy {
+    // 拼错的字段名和不认识的取值不会生效，也不算锁定，记入 rejected 并在菜单中列出
+    fn from_fields(mut fields: Map<String, Value>) -> Self {
+        let mut rejected = Vec::new();
+
+        // 间隔范围必须是正整数，且最短不能大于最长
+        let mut bound = |field: &str| {
+            let value = fields.remove(field)?;
+            let secs = value.as_u64().filter(|&secs| secs > 0);
+            if secs.is_none() {
+                rejected.push(field.to_string());
+            }
+            secs
+        };
+        let mut min_interval_secs = bound(MIN_INTERVAL_FIELD);
+        let mut max_interval_secs = bound(MAX_INTERVAL_FIELD);
+        if let (Some(min), Some(max)) = (min_interval_secs, max_interval_secs)
+            && min > max
+        {
+            rejected.extend([MIN_INTERVAL_FIELD.to_string(), MAX_INTERVAL_FIELD.to_string()]);
+            (min_interval_secs, max_interval_secs) = (None, None);
+        }
+
+        let (fields, invalid) = split_valid_fields(fields);
+        rejected.extend(invalid);
+        if !rejected.is_empty() {
+            eprintln!("Ignoring invalid policy fields: {}", rejected.join(", "));
+        }
+        Self { fields, rejected, min_interval_secs, max_interval_secs }
+    }
+}
+
+fn policy() -> &'static Policy {
+    POLICY.get_or_init(load)
+}
+
+// 管理员强制设置的字段，与设置文件中的字段同名，优先级高于命令行
+pub fn policy_fields() -> &'static Map<String, Value> {
+    &policy().fields
+}
+
+// 被策略锁定、不能在菜单中修改的字段，取值无效而被忽略的字段不算
+pub fn is_locked(field: &str) -> bool {
+    policy().fields.contains_key(field)
+}
+
+// 策略文件中取值无效、没有生效的字段
+pub fn rejected_policy_fields() -> &'static [String] {
+    &policy().rejected
+}
+
+// 策略是否限定了刷新间隔的范围
+pub fn has_interval_bounds() -> bool {
+    let policy = policy();
+    policy.min_interval_secs.is_some() || policy.max_interval_secs.is_some()
+}
+
+// 把刷新间隔（秒）限制在策略允许的范围内
+pub fn clamp_refresh_interval(secs: u64) -> u64 {
+    let policy = policy();
+    let secs = policy.min_interval_secs.map_or(secs, |min| secs.max(min));
+    policy.max_interval_secs.map_or(secs, |max| secs.min(max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_toml(content: &str) -> Policy {
+        Policy::from_fields(toml::from_str(content).unwrap())
+    }
+
+    #[test]
+    fn unknown_values_are_rejected_and_not_locked() {
+        let policy = from_toml("account_display = \"Redact\"\nicon_style = \"Bars\"\n");
+        assert!(!policy.fields.contains_key("account_display"));
+        assert!(policy.fields.contains_key("icon_style"));
+        assert_eq!(policy.rejected, vec!["account_display".to_string()]);
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let policy = from_toml("proxy = \"http://proxy.example.com\"\nrefresh_interval = 900\n");
+        assert_eq!(policy.fields.keys().collect::<Vec<_>>(), vec!["refresh_interval"]);
+        assert_eq!(policy.rejected, vec!["proxy".to_string()]);
+    }
+
+    #[test]
+    fn interval_bounds() {
+        let policy = from_toml("min_refresh_interval_secs = 300\nmax_refresh_interval_secs = 3600\n");
+        assert_eq!((policy.min_interval_secs, policy.max_interval_secs), (Some(300), Some(3600)));
+        assert!(policy.fields.is_empty() && policy.rejected.is_empty());
+
+        let policy = from_toml("min_refresh_interval_secs = 3600\nmax_refresh_interval_secs = 300\n");
+        assert_eq!((policy.min_interval_secs, policy.max_interval_secs), (None, None));
+        assert_eq!(policy.rejected.len(), 2);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::Path;
+
+// 电量不高于该百分比时视为低电量模式
+const LOW_BATTERY_PERCENT: u32 = 20;
+
+// 电源状态
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PowerState {
+    pub on_battery: bool,
+    pub low_power: bool,
+}
+
+// 从 sysfs 读取电源状态，root 通常为 /sys，测试时可以指向伪造的目录
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn read_power_state_in(sys_root: &Path) -> PowerState {
+    let Ok(entries) = fs::read_dir(sys_root.join("class").join("power_supply")) else {
+        return PowerState::default();
+    };
+
+    let read = |path: &Path, name: &str| fs::read_to_string(path.join(name)).map(|s| s.trim().to_string()).ok();
+
+    let mut has_battery = false;
+    let mut on_ac = false;
+    let mut capacity = None;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match read(&path, "type").as_deref() {
+            Some("Mains") | Some("USB") => on_ac |= read(&path, "online").as_deref() == Some("1"),
+            // 只统计系统电池，忽略鼠标、键盘等外设电池
+            Some("Battery") if read(&path, "scope").as_deref() != Some("Device") => {
+                has_battery = true;
+                if read(&path, "status").as_deref() == Some("Discharging") {
+                    capacity = read(&path, "capacity").and_then(|c| c.parse::<u32>().ok());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let on_battery = has_battery && !on_ac;
+    let power_saver = read(&sys_root.join("firmware").join("acpi"), "platform_profile").as_deref() == Some("low-power");
+    let low_battery = on_battery && capacity.is_some_and(|c| c <= LOW_BATTERY_PERCENT);
+
+    PowerState { on_battery, low_power: power_saver || low_battery }
+}
+
+pub fn read_power_state() -> PowerState {
+    #[cfg(target_os = "linux")]
+    {
+        read_power_state_in(Path::new("/sys"))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let run = |args: &[&str]| {
+            std::process::Command::new("pmset")
+                .args(args)
+                .output()
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+                .unwrap_or_default()
+        };
+        let battery = run(&["-g", "batt"]);
+        let settings = run(&["-g"]);
+        PowerState {
+            on_battery: battery.contains("'Battery Power'"),
+            low_power: settings.lines().any(|l| {
+                let mut parts = l.split_whitespace();
+                parts.next() == Some("lowpowermode") && parts.next() == Some("1")
+            }),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows_sys::Win32::System::Power::GetSystemPowerStatus;
+        use windows_sys::Win32::System::Power::SYSTEM_POWER_STATUS;
+
+        let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+        if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+            return PowerState::default();
+        }
+        // ACLineStatus: 0 表示使用电池；SystemStatusFlag: 1 表示开启了节电模式
+        PowerState { on_battery: status.ACLineStatus == 0, low_power: status.SystemStatusFlag == 1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TempDir;
+
+    // supplies 是 class/power_supply 下的设备及其属性文件
+    fn fake_sys(supplies: &[(&str, &[(&str, &str)])], platform_profile: Option<&str>) -> TempDir {
+        let root = TempDir::new();
+        for (supply, files) in supplies {
+            for (file, content) in *files {
+                root.write(&format!("class/power_supply/{}/{}", supply, file), format!("{}\n", content));
+            }
+        }
+        if let Some(profile) = platform_profile {
+            root.write("firmware/acpi/platform_profile", format!("{}\n", profile));
+        }
+        root
+    }
+
+    const MAINS_ONLINE: &[(&str, &str)] = &[("type", "Mains"), ("online", "1")];
+    const MAINS_OFFLINE: &[(&str, &str)] = &[("type", "Mains"), ("online", "0")];
+
+    #[test]
+    fn mains_online_is_not_on_battery() {
+        let battery: &[(&str, &str)] = &[("type", "Battery"), ("status", "Discharging"), ("capacity", "80")];
+        let root = fake_sys(&[("AC", MAINS_ONLINE), ("BAT0", battery)], None);
+        assert_eq!(read_power_state_in(root.path()), PowerState { on_battery: false, low_power: false });
+    }
+
+    #[test]
+    fn discharging_battery_at_low_capacity_is_low_power() {
+        let battery: &[(&str, &str)] = &[("type", "Battery"), ("status", "Discharging"), ("capacity", "20")];
+        let root = fake_sys(&[("AC", MAINS_OFFLINE), ("BAT0", battery)], None);
+        assert_eq!(read_power_state_in(root.path()), PowerState { on_battery: true, low_power: true });
+    }
+
+    #[test]
+    fn device_batteries_are_ignored() {
+        let mouse: &[(&str, &str)] = &[("type", "Battery"), ("scope", "Device"), ("status", "Discharging"), ("capacity", "5")];
+        let root = fake_sys(&[("hidpp_battery_0", mouse)], None);
+        assert_eq!(read_power_state_in(root.path()), PowerState { on_battery: false, low_power: false });
+    }
+
+    #[test]
+    fn low_power_platform_profile() {
+        let root = fake_sys(&[("AC", MAINS_ONLINE)], Some("low-power"));
+        assert_eq!(read_power_state_in(root.path()), PowerState { on_battery: false, low_power: true });
+    }
+}
@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+// 检查 Cursor 进程的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+// Cursor 未运行时的轮询策略
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum CursorClosedAction {
+    // 照常轮询
+    Poll,
+    // 按 cursor_closed_interval_secs 降低轮询频率
+    #[default]
+    Slow,
+    // 暂停轮询，直到 Cursor 再次启动
+    Pause,
+}
+
+// 在 procfs 中查找 Cursor 进程，root 通常为 /proc，测试时可以指向伪造的目录
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn is_cursor_running_in(proc_root: &Path) -> bool {
+    let Ok(entries) = fs::read_dir(proc_root) else {
+        return false;
+    };
+
+    entries.flatten().any(|entry| {
+        let name = entry.file_name();
+        if !name.to_string_lossy().chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+
+        let path = entry.path();
+        if let Ok(comm) = fs::read_to_string(path.join("comm"))
+            && is_cursor_name(comm.trim())
+        {
+            return true;
+        }
+
+        // comm 最长15个字符，再看一下命令行的第一个参数
+        fs::read(path.join("cmdline")).is_ok_and(|cmdline| {
+            let argv0 = cmdline.split(|&b| b == 0).next().unwrap_or_default();
+            let argv0 = String::from_utf8_lossy(argv0);
+            Path::new(argv0.as_ref()).file_name().is_some_and(|n| is_cursor_name(&n.to_string_lossy()))
+        })
+    })
+}
+
+fn is_cursor_name(name: &str) -> bool {
+    name.eq_ignore_ascii_case("cursor") || name.eq_ignore_ascii_case("cursor.exe")
+}
+
+pub fn is_cursor_running() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        is_cursor_running_in(Path::new("/proc"))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("pgrep")
+            .args(["-x", "Cursor"])
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // CREATE_NO_WINDOW，避免弹出控制台窗口
+        std::process::Command::new("tasklist")
+            .args(["/FI", "IMAGENAME eq Cursor.exe", "/NH"])
+            .creation_flags(0x08000000)
+            .output()
+            .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains("Cursor.exe"))
+    }
+}
+
+// 后台定期检查 Cursor 是否在运行，状态变化时回调
+#[derive(Clone)]
+pub struct CursorWatcher {
+    running: Arc<AtomicBool>,
+}
+
+impl CursorWatcher {
+    pub fn start<F>(on_change: F, shutdown_flag: Arc<AtomicBool>) -> Self
+    where
+        F: Fn(bool) + Send + 'static,
+    {
+        let running = Arc::new(AtomicBool::new(is_cursor_running()));
+        let watcher = Self { running: running.clone() };
+
+        thread::spawn(move || {
+            while !shutdown_flag.load(Ordering::Relaxed) {
+                thread::sleep(CHECK_INTERVAL);
+                let now_running = is_cursor_running();
+                if running.swap(now_running, Ordering::Relaxed) != now_running {
+                    on_change(now_running);
+                }
+            }
+        });
+
+        watcher
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TempDir;
+
+    // 每个进程是 (pid, comm, cmdline)，cmdline 的参数以 \0 分隔
+    fn fake_proc(processes: &[(&str, &str, &[u8])]) -> TempDir {
+        let root = TempDir::new();
+        for (pid, comm, cmdline) in processes {
+            root.write(&format!("{}/comm", pid), format!("{}\n", comm));
+            root.write(&format!("{}/cmdline", pid), cmdline);
+        }
+        root
+    }
+
+    #[test]
+    fn finds_cursor_by_comm() {
+        let root = fake_proc(&[("1", "systemd", b"/sbin/init\0"), ("42", "cursor", b"\0")]);
+        assert!(is_cursor_running_in(root.path()));
+    }
+
+    #[test]
+    fn finds_cursor_by_argv0() {
+        let root = fake_proc(&[("42", "electron", b"/opt/cursor/cursor\0--no-sandbox\0")]);
+        assert!(is_cursor_running_in(root.path()));
+    }
+
+    #[test]
+    fn ignores_non_numeric_entries() {
+        let root = fake_proc(&[("self", "cursor", b"/opt/cursor/cursor\0"), ("1", "systemd", b"/sbin/init\0")]);
+        assert!(!is_cursor_running_in(root.path()));
+    }
+
+    #[test]
+    fn no_cursor_process() {
+        let root = fake_proc(&[("1", "systemd", b"/sbin/init\0"), ("7", "code", b"/usr/share/code/code\0--cursor\0")]);
+        assert!(!is_cursor_running_in(root.path()));
+    }
+}
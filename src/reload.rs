@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::config::SharedState;
+use crate::settings::Settings;
+use crate::settings::get_config_path;
+
+// 检查设置文件是否被修改的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+// 文件的修改时间和大小，部分文件系统的修改时间精度只有1秒，再比较一下大小
+fn file_signature(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+// 在后台线程中监视设置文件，被外部编辑后立即应用新的设置
+pub fn watch_settings(state: &SharedState, shutdown_flag: Arc<AtomicBool>) {
+    let path = match get_config_path() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Failed to watch settings: {}", e);
+            return;
+        }
+    };
+
+    let state = state.clone();
+    thread::spawn(move || {
+        let mut last = file_signature(&path);
+        while !shutdown_flag.load(Ordering::Relaxed) {
+            thread::sleep(CHECK_INTERVAL);
+
+            let current = file_signature(&path);
+            if current == last {
+                continue;
+            }
+            last = current;
+            // 文件被删除时继续使用当前设置，下次保存时会重新创建
+            if current.is_none() {
+                continue;
+            }
+
+            // 本实例保存的设置与内存中相同，重新应用时不会产生任何变化
+            state.reload_settings(Settings::read(&path));
+        }
+    });
+}
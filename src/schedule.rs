@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Local;
+use chrono::NaiveTime;
+use chrono::Weekday;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::Language;
+use crate::config::format_duration;
+
+// 静默时段内的处理方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum QuietAction {
+    // 暂停轮询
+    #[default]
+    Pause,
+    // 按 slow_interval_secs 降低轮询频率
+    Slow,
+}
+
+// 每天的静默时间段，允许跨越午夜，例如 22:00-08:00
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: String,
+    pub end: String,
+}
+
+// 静默计划：夜间、周末等时段暂停或放慢轮询
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuietSchedule {
+    #[serde(default)]
+    pub hours: Option<QuietHours>,
+    // 整天静默的星期，例如 ["Sat", "Sun"]
+    #[serde(default)]
+    pub days: Vec<String>,
+    #[serde(default)]
+    pub action: QuietAction,
+    #[serde(default = "default_slow_interval_secs")]
+    pub slow_interval_secs: u64,
+}
+
+fn default_slow_interval_secs() -> u64 {
+    3600
+}
+
+impl QuietHours {
+    fn range(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let start = NaiveTime::parse_from_str(&self.start, "%H:%M").ok()?;
+        let end = NaiveTime::parse_from_str(&self.end, "%H:%M").ok()?;
+        Some((start, end))
+    }
+
+    fn contains(&self, time: NaiveTime) -> bool {
+        match self.range() {
+            Some((start, end)) if start <= end => time >= start && time < end,
+            Some((start, end)) => time >= start || time < end,
+            None => false,
+        }
+    }
+}
+
+impl QuietSchedule {
+    fn weekdays(&self) -> Vec<Weekday> {
+        self.days.iter().filter_map(|d| Weekday::from_str(d).ok()).collect()
+    }
+
+    pub fn is_quiet(&self, now: DateTime<Local>) -> bool {
+        self.weekdays().contains(&now.weekday()) || self.hours.as_ref().is_some_and(|h| h.contains(now.time()))
+    }
+
+    // 无法解析的时间或星期会被忽略，这里给出提示
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(hours) = &self.hours
+            && hours.range().is_none()
+        {
+            return Err(format!("Invalid quiet hours: {}-{}, expected HH:MM", hours.start, hours.end));
+        }
+        if let Some(day) = self.days.iter().find(|d| Weekday::from_str(d).is_err()) {
+            return Err(format!("Invalid quiet day: {}", day));
+        }
+        Ok(())
+    }
+
+    // 菜单中显示的静默计划说明
+    pub fn describe(&self, lang: Language) -> String {
+        let mut parts = Vec::new();
+        if let Some(hours) = &self.hours {
+            parts.push(format!("{}–{}", hours.start, hours.end));
+        }
+        for day in self.weekdays() {
+            parts.push(lang.format_weekday(day));
+        }
+
+        let action = match self.action {
+            QuietAction::Pause => lang.tr("quiet-paused"),
+            QuietAction::Slow => lang.tr_args("quiet-slow", &[("duration", format_duration(self.slow_interval_secs, lang).into())]),
+        };
+        let when = parts.join(&lang.tr("list-separator"));
+        lang.tr_args("quiet-hours", &[("when", when.into()), ("action", action.into())])
+    }
+}
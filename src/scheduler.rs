@@ -0,0 +1,188 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::adaptive::AdaptiveScheduler;
+use crate::config::RefreshInterval;
+use crate::config::SharedState;
+use crate::events::StateEvent;
+use crate::power::read_power_state;
+use crate::process::CursorWatcher;
+use crate::wake::WakeDetector;
+
+// 暂停期间检查是否恢复轮询的间隔
+const PAUSE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+// 等待下次刷新期间检查关闭信号、睡眠唤醒和网络变化的间隔
+const WAKE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+enum Request {
+    // 事件循环启动后发送，托盘此时已订阅事件，不会错过第一次刷新的结果
+    Start,
+    // 用户手动刷新，暂停时也会刷新
+    Refresh,
+    // 外部变化（如 Cursor 启动或退出）触发的刷新，暂停时忽略
+    Poll,
+    // 设置变化后重新计算下次刷新时间
+    Reschedule,
+}
+
+enum Wakeup {
+    Due,
+    Requested,
+    Polled,
+    Rescheduled,
+    Shutdown,
+}
+
+// 向刷新线程发送请求，可以在任意线程中使用
+#[derive(Clone)]
+pub struct RefreshHandle {
+    sender: Sender<Request>,
+}
+
+impl RefreshHandle {
+    pub fn start(&self) {
+        let _ = self.sender.send(Request::Start);
+    }
+
+    pub fn request_refresh(&self) {
+        let _ = self.sender.send(Request::Refresh);
+    }
+}
+
+// 唯一的刷新线程：所有刷新请求都在这里串行执行，排队中的重复请求会被合并
+pub struct RefreshScheduler {
+    state: SharedState,
+    adaptive: AdaptiveScheduler,
+    wake: WakeDetector,
+    requests: Receiver<Request>,
+    shutdown_flag: Arc<AtomicBool>,
+}
+
+impl RefreshScheduler {
+    pub fn start(state: &SharedState, shutdown_flag: Arc<AtomicBool>) -> RefreshHandle {
+        let (sender, requests) = mpsc::channel();
+
+        // Cursor 启动或退出时立即刷新一次
+        let watcher_sender = sender.clone();
+        let watcher_state = state.clone();
+        let watcher = CursorWatcher::start(
+            move |running| {
+                watcher_state.set_cursor_running(running);
+                let _ = watcher_sender.send(Request::Poll);
+            },
+            shutdown_flag.clone(),
+        );
+        state.set_cursor_running(watcher.is_running());
+
+        // 刷新间隔、暂停等设置变化时立即重新安排，不必等当前的等待结束
+        let settings_sender = sender.clone();
+        state.subscribe(move |event| {
+            if matches!(event, StateEvent::SettingsChanged(_)) {
+                let _ = settings_sender.send(Request::Reschedule);
+            }
+        });
+
+        let scheduler = Self {
+            state: state.clone(),
+            adaptive: AdaptiveScheduler::attach(state),
+            wake: WakeDetector::new(),
+            requests,
+            shutdown_flag,
+        };
+        thread::spawn(move || scheduler.run());
+
+        RefreshHandle { sender }
+    }
+
+    fn run(mut self) {
+        // 等待启动信号，之前收到的其他请求由第一次刷新满足
+        loop {
+            match self.requests.recv() {
+                Ok(Request::Start) => break,
+                Ok(_) => {}
+                Err(_) => return,
+            }
+        }
+
+        // 启动后立即刷新一次
+        let mut last_refresh: Option<Instant> = None;
+        let mut requested = false;
+
+        while !self.shutdown_flag.load(Ordering::Relaxed) {
+            // 暂停期间每隔 PAUSE_CHECK_INTERVAL 醒来一次，到期的暂停只在这里清除
+            self.state.clear_expired_pause();
+            // 读取电源状态可能需要启动外部命令，每次醒来只读一次
+            self.state.set_power_state(read_power_state());
+            let (paused, interval) = self.policy();
+            let due = last_refresh.is_none_or(|t| t.elapsed() >= interval);
+
+            if requested || (due && !paused) {
+                if let Err(e) = self.state.update_usage_data() {
+                    eprintln!("Failed to update data: {}", e);
+                }
+                last_refresh = Some(Instant::now());
+                requested = false;
+                // 刷新期间排队的请求已由本次刷新满足，直接合并
+                while self.requests.try_recv().is_ok() {}
+                continue;
+            }
+
+            let deadline = match last_refresh {
+                Some(t) if !paused => t + interval,
+                _ => Instant::now() + PAUSE_CHECK_INTERVAL,
+            };
+            match self.wait_until(deadline) {
+                Wakeup::Requested => requested = true,
+                // 视为到期，暂停时仍然不会刷新
+                Wakeup::Polled => last_refresh = None,
+                Wakeup::Due | Wakeup::Rescheduled => {}
+                Wakeup::Shutdown => return,
+            }
+        }
+    }
+
+    // 按截止时间等待，期间定期检查关闭信号、睡眠唤醒和网络变化
+    fn wait_until(&mut self, deadline: Instant) -> Wakeup {
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            match self.requests.recv_timeout(remaining.min(WAKE_CHECK_INTERVAL)) {
+                Ok(Request::Refresh) | Ok(Request::Start) => return Wakeup::Requested,
+                Ok(Request::Poll) => return Wakeup::Polled,
+                Ok(Request::Reschedule) => return Wakeup::Rescheduled,
+                Err(RecvTimeoutError::Disconnected) => return Wakeup::Shutdown,
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+            if self.shutdown_flag.load(Ordering::Relaxed) {
+                return Wakeup::Shutdown;
+            }
+            if self.wake.poll().is_some() {
+                return Wakeup::Polled;
+            }
+        }
+        Wakeup::Due
+    }
+
+    // 当前是否暂停轮询，以及两次刷新之间的间隔
+    fn policy(&self) -> (bool, Duration) {
+        let state = &self.state;
+
+        let adaptive = match state.get_refresh_interval() {
+            RefreshInterval::Adaptive => {
+                let (min, max) = state.get_adaptive_bounds();
+                let thresholds = state.get_usage_levels(&state.get_usage_data()).thresholds();
+                Some(self.adaptive.next_interval(Duration::from_secs(min), Duration::from_secs(max), &thresholds).as_secs())
+            }
+            _ => None,
+        };
+        let (paused, interval) = state.poll_policy(adaptive);
+        (paused, Duration::from_secs(interval))
+    }
+}
@@ -1,55 +1,660 @@
+use std::env;
 use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use chrono::Local;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use toml_edit::DocumentMut;
 
-use crate::config::Language;
+use crate::config::AccountDisplay;
+use crate::config::ColorMode;
+use crate::config::IconStyle;
+use crate::config::LanguagePreference;
+use crate::config::MAX_INTERVAL_SECS;
+use crate::config::MIN_INTERVAL_SECS;
+use crate::config::PauseState;
 use crate::config::RefreshInterval;
+use crate::levels::LevelPreset;
+use crate::levels::UsageLevel;
+use crate::levels::UsageLevelRule;
+use crate::levels::validate_levels;
+use crate::policy::clamp_refresh_interval;
+use crate::policy::is_locked;
+use crate::policy::policy_fields;
+use crate::process::CursorClosedAction;
+use crate::schedule::QuietSchedule;
 
-#[derive(Debug, Serialize, Deserialize)]
+// 当前的设置文件版本，结构变化需要迁移时递增，并在 MIGRATIONS 中追加一步
+pub const SETTINGS_VERSION: u32 = 2;
+
+// 覆盖单个设置字段的环境变量前缀，例如 CURSORBAR_REFRESH_INTERVAL=60
+const ENV_PREFIX: &str = "CURSORBAR_";
+// 指定设置文件路径的环境变量，不是设置字段
+const ENV_CONFIG: &str = "CURSORBAR_CONFIG";
+
+// 启动时通过 configure 确定，之后不再变化
+static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
+static OVERRIDES: OnceLock<Map<String, Value>> = OnceLock::new();
+// 取值无效、没有生效的覆盖，读取设置时一起报告
+static REJECTED_OVERRIDES: OnceLock<Vec<String>> = OnceLock::new();
+
+// MIGRATIONS[i] 把版本 i + 1 的设置迁移到版本 i + 2
+const MIGRATIONS: &[fn(&mut Map<String, Value>)] = &[migrate_v1_to_v2];
+
+// 版本 1 没有 version 字段，缺少 language 时使用的是中文，而现在的默认值是跟随系统
+fn migrate_v1_to_v2(settings: &mut Map<String, Value>) {
+    settings.entry("language").or_insert_with(|| Value::from("Chinese"));
+}
+
+// 读取设置时发现的问题，会显示在菜单中
+#[derive(Clone, Debug, PartialEq)]
+pub enum SettingsWarning {
+    // 设置文件无法解析，已备份并改用默认设置
+    Corrupted { backup: PathBuf },
+    // 这些字段的取值无效，已改用默认值
+    InvalidFields(Vec<String>),
+    // 外部修改后的设置文件无法解析，继续使用当前设置
+    ReloadFailed(String),
+    // 设置文件无法读取或备份，暂时使用默认设置
+    Unreadable(String),
+}
+
+// 缺少的字段使用默认值，不认识的字段原样保留
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
+    pub version: u32,
     pub language: String,
     pub refresh_interval: u64,
+    // 使用电池和低电量模式下的最短刷新间隔（秒），0 表示不做限制
+    #[serde(default = "default_battery_interval_secs")]
+    pub battery_interval_secs: u64,
+    #[serde(default = "default_low_power_interval_secs")]
+    pub low_power_interval_secs: u64,
+    #[serde(default = "default_adaptive_min_secs")]
+    pub adaptive_min_secs: u64,
+    #[serde(default = "default_adaptive_max_secs")]
+    pub adaptive_max_secs: u64,
+    // 夜间、周末等静默时段，未设置时始终按刷新间隔轮询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_schedule: Option<QuietSchedule>,
+    // Cursor 未运行时用量不会变化，可以放慢或暂停轮询
+    #[serde(default)]
+    pub cursor_closed_action: CursorClosedAction,
+    #[serde(default = "default_cursor_closed_interval_secs")]
+    pub cursor_closed_interval_secs: u64,
+    #[serde(default = "default_color_mode")]
+    pub color_mode: String,
+    #[serde(default)]
+    pub pace_skip_weekends: bool,
+    #[serde(default = "default_icon_style")]
+    pub icon_style: String,
+    // 图标颜色等级：内置预设，或 Custom 表示使用 usage_levels
+    #[serde(default = "default_level_preset")]
+    pub level_preset: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub usage_levels: Vec<UsageLevel>,
+    // 按模型或账户覆盖等级，第一条匹配的规则生效
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub usage_level_rules: Vec<UsageLevelRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pause: Option<PauseState>,
+    // 菜单中账户邮箱的显示方式：Full、Redacted 或 Hidden
+    #[serde(default = "default_account_display")]
+    pub account_display: String,
+    // 更新版本写入的字段
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+fn default_battery_interval_secs() -> u64 {
+    RefreshInterval::Min10.as_secs()
+}
+
+fn default_low_power_interval_secs() -> u64 {
+    RefreshInterval::Min30.as_secs()
+}
+
+fn default_adaptive_min_secs() -> u64 {
+    RefreshInterval::Min1.as_secs()
+}
+
+fn default_adaptive_max_secs() -> u64 {
+    RefreshInterval::Hour1.as_secs()
+}
+
+fn default_cursor_closed_interval_secs() -> u64 {
+    RefreshInterval::Min30.as_secs()
+}
+
+fn default_color_mode() -> String {
+    ColorMode::Absolute.to_string()
+}
+
+fn default_icon_style() -> String {
+    IconStyle::Disc.to_string()
+}
+
+fn default_account_display() -> String {
+    AccountDisplay::Full.to_string()
+}
+
+fn default_level_preset() -> String {
+    LevelPreset::Standard.to_string()
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Self { language: Language::Chinese.to_string(), refresh_interval: RefreshInterval::Min5.as_secs() }
+        Self {
+            version: SETTINGS_VERSION,
+            language: LanguagePreference::System.to_string(),
+            refresh_interval: RefreshInterval::Min5.as_secs(),
+            battery_interval_secs: default_battery_interval_secs(),
+            low_power_interval_secs: default_low_power_interval_secs(),
+            adaptive_min_secs: default_adaptive_min_secs(),
+            adaptive_max_secs: default_adaptive_max_secs(),
+            quiet_schedule: None,
+            cursor_closed_action: CursorClosedAction::default(),
+            cursor_closed_interval_secs: default_cursor_closed_interval_secs(),
+            color_mode: default_color_mode(),
+            pace_skip_weekends: false,
+            icon_style: default_icon_style(),
+            level_preset: default_level_preset(),
+            usage_levels: Vec::new(),
+            usage_level_rules: Vec::new(),
+            pause: None,
+            account_display: default_account_display(),
+            extra: Map::new(),
+        }
     }
 }
 
 impl Settings {
-    pub fn load() -> Result<Self> {
+    pub fn load() -> Result<(Self, Option<SettingsWarning>)> {
         let config_path = get_config_path()?;
 
         if !config_path.exists() {
-            return Ok(Self::default());
+            let (settings, invalid) = Self::default().with_overrides();
+            return Ok((settings, invalid_fields_warning(invalid)));
+        }
+
+        match Self::read(&config_path) {
+            Ok((settings, invalid)) => Ok((settings, invalid_fields_warning(invalid))),
+            // 无法解析时备份原文件，避免下次保存时覆盖用户的设置
+            Err(e) => {
+                eprintln!("Failed to parse settings: {}", e);
+                let backup = backup_path(&config_path);
+                let (settings, _) = Self::default().with_overrides();
+                // 无法备份时也要告诉用户，否则会以为设置丢失了
+                if let Err(e) = fs::rename(&config_path, &backup) {
+                    eprintln!("Failed to back up settings: {}", e);
+                    return Ok((settings, Some(SettingsWarning::Unreadable(e.to_string()))));
+                }
+                Ok((settings, Some(SettingsWarning::Corrupted { backup })))
+            }
         }
+    }
 
-        let content = fs::read_to_string(&config_path)?;
-        let settings = serde_json::from_str(&content)?;
-        Ok(settings)
+    // 读取设置文件并叠加环境变量和命令行中的覆盖
+    pub fn read(path: &Path) -> Result<(Self, Vec<String>)> {
+        let content = fs::read_to_string(path)?;
+        let (settings, mut invalid) = Self::parse(&content, ConfigFormat::from_path(path))?;
+        let (settings, overridden) = settings.with_overrides();
+        invalid.extend(overridden);
+        Ok((settings, invalid))
     }
 
-    pub fn save(&self) -> Result<()> {
+    // 解析并迁移设置文件，同时返回取值无效而被忽略的字段
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<(Self, Vec<String>)> {
+        let mut fields = format.parse(content)?;
+
+        // TOML 格式从版本 2 开始支持，没有 version 字段时不需要迁移
+        let unversioned = if format == ConfigFormat::Toml { SETTINGS_VERSION } else { 1 };
+        let version = fields.get("version").and_then(Value::as_u64).map_or(unversioned, |v| v as u32);
+        if version > SETTINGS_VERSION {
+            eprintln!("Settings were written by a newer version ({}), unknown fields are kept", version);
+        }
+        for migrate in MIGRATIONS.iter().skip(version.saturating_sub(1) as usize) {
+            migrate(&mut fields);
+        }
+        fields.insert("version".to_string(), Value::from(version.max(SETTINGS_VERSION)));
+
+        Self::default().merge_fields(fields)
+    }
+
+    // 按 默认值 < 设置文件 < 环境变量 < 命令行 < 策略文件 的顺序叠加覆盖，取值无效的覆盖会被忽略
+    pub fn with_overrides(self) -> (Self, Vec<String>) {
+        let mut overrides = OVERRIDES.get().cloned().unwrap_or_default();
+        overrides.extend(policy_fields().clone());
+        let rejected = REJECTED_OVERRIDES.get().cloned().unwrap_or_default();
+        let (mut settings, mut invalid) = if overrides.is_empty() {
+            (self, Vec::new())
+        } else {
+            match self.clone().merge_fields(overrides) {
+                Ok(overridden) => overridden,
+                Err(e) => {
+                    eprintln!("Failed to apply setting overrides: {}", e);
+                    (self, Vec::new())
+                }
+            }
+        };
+        invalid.extend(rejected);
+
+        // 策略限定了刷新间隔范围时，固定间隔和自适应模式的上下限都不能超出范围
+        if settings.refresh_interval != RefreshInterval::Adaptive.as_secs() {
+            settings.refresh_interval = clamp_refresh_interval(settings.refresh_interval);
+        }
+        settings.adaptive_min_secs = clamp_refresh_interval(settings.adaptive_min_secs);
+        settings.adaptive_max_secs = clamp_refresh_interval(settings.adaptive_max_secs);
+        (settings, invalid)
+    }
+
+    // 逐个字段合并到当前设置上，类型不对的字段保留原来的值
+    fn merge_fields(self, fields: Map<String, Value>) -> Result<(Self, Vec<String>)> {
+        let mut merged = to_map(&self)?;
+        let mut invalid = Vec::new();
+        for (key, value) in fields {
+            let previous = merged.insert(key.clone(), value);
+            if serde_json::from_value::<Self>(Value::Object(merged.clone())).is_err() {
+                match previous {
+                    Some(previous) => merged.insert(key.clone(), previous),
+                    None => merged.remove(&key),
+                };
+                invalid.push(key);
+            }
+        }
+
+        let mut settings: Self = serde_json::from_value(Value::Object(merged))?;
+        settings.validate(&mut invalid);
+        Ok((settings, invalid))
+    }
+
+    // 修正手工编辑导致的不合理取值，无法修正的字段改用默认值并记入 invalid
+    fn validate(&mut self, invalid: &mut Vec<String>) {
+        // 不认识的取值运行时按默认值处理，文件中的值保留，但需要报告
+        let known = [
+            ("language", LanguagePreference::from_str(&self.language).to_string() == self.language),
+            ("color_mode", ColorMode::from_str(&self.color_mode).to_string() == self.color_mode),
+            ("icon_style", IconStyle::from_str(&self.icon_style).to_string() == self.icon_style),
+            ("level_preset", LevelPreset::from_str(&self.level_preset).to_string() == self.level_preset),
+            ("account_display", AccountDisplay::from_str(&self.account_display).to_string() == self.account_display),
+        ];
+        for (field, valid) in known {
+            if !valid {
+                eprintln!("Unknown value for {}", field);
+                invalid.push(field.to_string());
+            }
+        }
+
+        // 超出范围的刷新间隔运行时按边界使用，文件中的值保留到用户在菜单中修改
+        if self.refresh_interval != RefreshInterval::Adaptive.as_secs()
+            && !(MIN_INTERVAL_SECS..=MAX_INTERVAL_SECS).contains(&self.refresh_interval)
+        {
+            eprintln!(
+                "Refresh interval {} is out of range, expected {}-{} seconds",
+                self.refresh_interval, MIN_INTERVAL_SECS, MAX_INTERVAL_SECS
+            );
+            invalid.push("refresh_interval".to_string());
+        }
+        self.adaptive_min_secs = self.adaptive_min_secs.max(MIN_INTERVAL_SECS);
+        self.adaptive_max_secs = self.adaptive_max_secs.max(self.adaptive_min_secs);
+        for secs in [&mut self.battery_interval_secs, &mut self.low_power_interval_secs] {
+            if *secs != 0 {
+                *secs = (*secs).clamp(MIN_INTERVAL_SECS, MAX_INTERVAL_SECS);
+            }
+        }
+        self.cursor_closed_interval_secs = self.cursor_closed_interval_secs.clamp(MIN_INTERVAL_SECS, MAX_INTERVAL_SECS);
+        if let Some(schedule) = &mut self.quiet_schedule {
+            if let Err(e) = schedule.validate() {
+                eprintln!("{}", e);
+            }
+            schedule.slow_interval_secs = schedule.slow_interval_secs.clamp(MIN_INTERVAL_SECS, MAX_INTERVAL_SECS);
+        }
+
+        if !self.usage_levels.is_empty()
+            && let Err(e) = validate_levels(&self.usage_levels)
+        {
+            eprintln!("{}", e);
+            self.usage_levels.clear();
+            invalid.push("usage_levels".to_string());
+        }
+        let rule_count = self.usage_level_rules.len();
+        self.usage_level_rules.retain(|rule| match validate_levels(&rule.levels) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        });
+        if self.usage_level_rules.len() != rule_count {
+            invalid.push("usage_level_rules".to_string());
+        }
+    }
+
+    // 保存时与磁盘上的设置合并，返回实际生效的设置（包括环境变量和命令行中的覆盖）
+    // 磁盘上的文件无法解析时先备份，同时返回需要在菜单中显示的警告
+    // base 是上次读取或保存时的设置，用来判断哪些字段是本进程修改的，未修改的覆盖不会写入文件
+    pub fn save(&self, base: &Settings) -> Result<(Settings, Option<SettingsWarning>)> {
         let config_path = get_config_path()?;
+        let config_dir = config_path.parent().ok_or_else(|| anyhow!("Invalid settings path: {}", config_path.display()))?;
+        let format = ConfigFormat::from_path(&config_path);
 
         // 确保目录存在
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
+        fs::create_dir_all(config_dir)?;
+
+        // 多个实例同时保存时依次进行，锁在函数返回时释放
+        let lock = OpenOptions::new().create(true).truncate(false).write(true).open(config_dir.join(".settings.lock"))?;
+        lock.lock()?;
+
+        // 磁盘上没有可用的设置时与默认设置合并
+        let mut warning = None;
+        let mut existing = fs::read_to_string(&config_path).ok();
+        let disk = match existing.as_deref().map(|content| Self::parse(content, format)) {
+            Some(Ok((disk, _))) => disk,
+            Some(Err(e)) => {
+                eprintln!("Failed to parse settings on disk, backing it up: {}", e);
+                let backup = backup_path(&config_path);
+                fs::rename(&config_path, &backup)?;
+                warning = Some(SettingsWarning::Corrupted { backup });
+                existing = None;
+                Self::default()
+            }
+            None => Self::default(),
+        };
+        let merged = self.merge(base, &disk)?;
+
+        let content = match (format, existing) {
+            (ConfigFormat::Toml, Some(existing)) => update_toml(&existing, &disk, &merged)?,
+            (ConfigFormat::Toml, None) => toml::to_string_pretty(&merged)?,
+            (ConfigFormat::Json, _) => serde_json::to_string_pretty(&merged)?,
+        };
+        write_atomic(&config_path, content.as_bytes())?;
+        Ok((merged.with_overrides().0, warning))
+    }
+
+    // 三方合并：本进程修改过的字段使用自己的值，其余字段以磁盘上的为准
+    fn merge(&self, base: &Settings, disk: &Settings) -> Result<Settings> {
+        let (base, ours, mut merged) = (to_map(base)?, to_map(self)?, to_map(disk)?);
+
+        for key in base.keys().chain(ours.keys()) {
+            // 被覆盖或锁定的字段只在本次运行中生效，保留文件中的值
+            if is_overridden(key) || is_locked(key) {
+                continue;
+            }
+            if base.get(key) != ours.get(key) {
+                match ours.get(key) {
+                    Some(value) => merged.insert(key.clone(), value.clone()),
+                    None => merged.remove(key),
+                };
+            }
+        }
+        Ok(serde_json::from_value(Value::Object(merged))?)
+    }
+}
+
+// 把要覆盖的字段逐个叠加到默认设置上检查，返回有效的字段和取值无效的字段名
+// 不是设置字段的名称（例如拼错的环境变量）也视为无效，它们只会进入 extra，不会生效
+pub fn split_valid_fields(fields: Map<String, Value>) -> (Map<String, Value>, Vec<String>) {
+    let invalid = match Settings::default().merge_fields(fields.clone()) {
+        Ok((settings, mut invalid)) => {
+            invalid.extend(settings.extra.into_iter().map(|(key, _)| key));
+            invalid
+        }
+        Err(_) => fields.keys().cloned().collect(),
+    };
+    let valid = fields.into_iter().filter(|(key, _)| !invalid.contains(key)).collect();
+    (valid, invalid)
+}
+
+fn to_map(settings: &Settings) -> Result<Map<String, Value>> {
+    match serde_json::to_value(settings)? {
+        Value::Object(map) => Ok(map),
+        _ => Err(anyhow!("Settings must serialize to a JSON object")),
+    }
+}
+
+fn invalid_fields_warning(invalid: Vec<String>) -> Option<SettingsWarning> {
+    if invalid.is_empty() {
+        return None;
+    }
+    eprintln!("Ignoring invalid settings: {}", invalid.join(", "));
+    Some(SettingsWarning::InvalidFields(invalid))
+}
+
+// 设置文件格式，按扩展名区分
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigFormat {
+    Json,
+    // 可以写注释，保存时保留未修改的部分
+    Toml,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    fn parse(&self, content: &str) -> Result<Map<String, Value>> {
+        match self {
+            ConfigFormat::Json => match serde_json::from_str(content)? {
+                Value::Object(fields) => Ok(fields),
+                _ => Err(anyhow!("Settings must be a JSON object")),
+            },
+            ConfigFormat::Toml => Ok(toml::from_str(content)?),
+        }
+    }
+}
+
+// 只写入取值有变化的字段，保留用户在 TOML 文件中写的注释和排版，省略的字段继续省略
+fn update_toml(existing: &str, disk: &Settings, settings: &Settings) -> Result<String> {
+    let Ok(mut document) = existing.parse::<DocumentMut>() else {
+        return Ok(toml::to_string_pretty(settings)?);
+    };
+    let old = to_map(disk)?;
+    let new = to_map(settings)?;
+    let updated = toml::to_string_pretty(settings)?.parse::<DocumentMut>()?;
+
+    for (key, item) in updated.iter() {
+        if old.get(key) != new.get(key) || (key == "version" && !document.contains_key(key)) {
+            document.insert(key, item.clone());
         }
+    }
+    let removed: Vec<String> = document.iter().map(|(key, _)| key.to_string()).filter(|key| !new.contains_key(key)).collect();
+    for key in removed {
+        document.remove(&key);
+    }
+    Ok(document.to_string())
+}
+
+// 先写临时文件并同步到磁盘，再重命名替换，避免崩溃时留下写了一半的文件
+fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("settings.json");
+    let temp_path = path.with_file_name(format!("{}.tmp", name));
+    let mut file = File::create(&temp_path)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&temp_path, path)?;
 
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(config_path, content)?;
-        Ok(())
+    // 同步目录，确保重命名本身也已落盘
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        File::open(parent)?.sync_all()?;
     }
+    Ok(())
 }
 
-fn get_config_path() -> Result<PathBuf> {
+// 应用配置目录，设置文件和历史数据库都存放在这里
+pub fn get_config_dir() -> Result<PathBuf> {
     let config_dir = dirs::config_dir().ok_or_else(|| anyhow!("Cannot get configuration directory"))?;
 
-    Ok(config_dir.join("CursorBarWatch").join("settings.json"))
+    Ok(config_dir.join("CursorBarWatch"))
+}
+
+// 启动时调用一次，确定设置文件的路径和覆盖设置的环境变量及命令行参数
+// 路径优先使用 --config，其次是 CURSORBAR_CONFIG
+pub fn configure(config_path: Option<PathBuf>, cli_overrides: Vec<(String, String)>) {
+    if let Some(path) = config_path.or_else(|| env::var_os(ENV_CONFIG).map(PathBuf::from)) {
+        let _ = CONFIG_PATH.set(path);
+    }
+
+    // 环境变量名去掉前缀后转为小写即为字段名，命令行参数最后插入，优先级最高
+    let env_overrides = env::vars_os().filter_map(|(key, value)| {
+        let key = key.into_string().ok()?;
+        if key == ENV_CONFIG {
+            return None;
+        }
+        Some((key.strip_prefix(ENV_PREFIX)?.to_ascii_lowercase(), value.into_string().ok()?))
+    });
+    let mut overrides = Map::new();
+    for (key, value) in env_overrides.chain(cli_overrides) {
+        overrides.insert(key, parse_override(&value));
+    }
+    let (overrides, rejected) = split_valid_fields(overrides);
+    let _ = OVERRIDES.set(overrides);
+    let _ = REJECTED_OVERRIDES.set(rejected);
+}
+
+// 被环境变量或命令行覆盖的字段，本次运行中不能在菜单中修改
+pub fn is_overridden(field: &str) -> bool {
+    OVERRIDES.get().is_some_and(|overrides| overrides.contains_key(field))
+}
+
+// 能按 JSON 解析的取值（数字、布尔值、对象等）按 JSON 处理，其余视为字符串
+fn parse_override(value: &str) -> Value {
+    serde_json::from_str(value).unwrap_or_else(|_| Value::from(value))
+}
+
+// 有 settings.toml 时优先使用，否则使用 settings.json
+pub fn get_config_path() -> Result<PathBuf> {
+    if let Some(path) = CONFIG_PATH.get() {
+        return Ok(path.clone());
+    }
+    let config_dir = get_config_dir()?;
+    let toml_path = config_dir.join("settings.toml");
+    if toml_path.exists() {
+        return Ok(toml_path);
+    }
+    Ok(config_dir.join("settings.json"))
+}
+
+// settings.json.bak-<时间戳>
+fn backup_path(config_path: &Path) -> PathBuf {
+    let name = config_path.file_name().and_then(|n| n.to_str()).unwrap_or("settings.json");
+    config_path.with_file_name(format!("{}.bak-{}", name, Local::now().format("%Y%m%d-%H%M%S")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TempDir;
+
+    #[test]
+    fn v1_file_without_language_migrates_to_chinese() {
+        let (settings, invalid) = Settings::parse(r#"{"refresh_interval": 60}"#, ConfigFormat::Json).unwrap();
+        assert_eq!(settings.language, "Chinese");
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert_eq!(settings.refresh_interval, 60);
+        assert!(invalid.is_empty());
+    }
+
+    #[test]
+    fn wrong_type_keeps_default_and_is_reported() {
+        let content = r#"{"version": 2, "refresh_interval": "fast", "icon_style": "Bars"}"#;
+        let (settings, invalid) = Settings::parse(content, ConfigFormat::Json).unwrap();
+        assert_eq!(settings.refresh_interval, Settings::default().refresh_interval);
+        assert_eq!(settings.icon_style, "Bars");
+        assert_eq!(invalid, vec!["refresh_interval".to_string()]);
+    }
+
+    #[test]
+    fn out_of_range_interval_is_reported_and_kept() {
+        let (settings, invalid) = Settings::parse(r#"{"version": 2, "refresh_interval": 10}"#, ConfigFormat::Json).unwrap();
+        assert_eq!(settings.refresh_interval, 10);
+        assert_eq!(invalid, vec!["refresh_interval".to_string()]);
+    }
+
+    #[test]
+    fn unknown_fields_are_kept() {
+        let content = "version = 3\nlanguage = \"English\"\nproxy = \"http://127.0.0.1:8080\"\n";
+        let (settings, invalid) = Settings::parse(content, ConfigFormat::Toml).unwrap();
+        assert_eq!(settings.language, "English");
+        assert_eq!(settings.extra.get("proxy"), Some(&Value::from("http://127.0.0.1:8080")));
+        assert!(invalid.is_empty());
+    }
+
+    #[test]
+    fn unknown_values_and_fields_are_rejected() {
+        let fields: Map<String, Value> =
+            serde_json::from_str(r#"{"language": "de", "account_display": "Redact", "refersh_interval": 60, "icon_style": "Bars"}"#)
+                .unwrap();
+        let (valid, mut invalid) = split_valid_fields(fields);
+        invalid.sort();
+        assert_eq!(valid.keys().collect::<Vec<_>>(), vec!["icon_style"]);
+        assert_eq!(invalid, vec!["account_display", "language", "refersh_interval"]);
+    }
+
+    #[test]
+    fn save_does_not_write_overrides() {
+        let dir = TempDir::new();
+        dir.write("settings.json", r#"{"version": 2, "color_mode": "Absolute", "refresh_interval": 600}"#);
+        let path = dir.path().join("settings.json");
+        configure(Some(path.clone()), vec![("color_mode".to_string(), "Pace".to_string())]);
+
+        let (settings, invalid) = Settings::read(&path).unwrap();
+        assert_eq!(settings.color_mode, "Pace");
+        assert!(invalid.is_empty());
+
+        // 被覆盖的字段即使在本次运行中被修改也不写入文件，其他字段正常保存
+        let before = Settings::parse(&fs::read_to_string(&path).unwrap(), ConfigFormat::Json).unwrap();
+        let ours = Settings { icon_style: "Bars".to_string(), color_mode: "Absolute".to_string(), ..settings.clone() };
+        let (saved, warning) = ours.save(&settings).unwrap();
+        assert_eq!(warning, None);
+        assert_eq!(saved.color_mode, "Pace");
+        assert_eq!(saved.icon_style, "Bars");
+
+        let (after, _) = Settings::parse(&fs::read_to_string(&path).unwrap(), ConfigFormat::Json).unwrap();
+        assert_eq!(after, Settings { icon_style: "Bars".to_string(), ..before.0 });
+    }
+
+    #[test]
+    fn merge_keeps_changes_from_another_instance() {
+        let base = Settings::default();
+        let ours = Settings { icon_style: "Bars".to_string(), ..base.clone() };
+        let disk = Settings { language: "English".to_string(), ..base.clone() };
+        let merged = ours.merge(&base, &disk).unwrap();
+        assert_eq!(merged.icon_style, "Bars");
+        assert_eq!(merged.language, "English");
+    }
+
+    #[test]
+    fn merge_prefers_our_change_to_our_field() {
+        let base = Settings::default();
+        let ours = Settings { refresh_interval: 60, ..base.clone() };
+        let disk = Settings { refresh_interval: 1800, ..base.clone() };
+        assert_eq!(ours.merge(&base, &disk).unwrap().refresh_interval, 60);
+    }
+
+    #[test]
+    fn merge_removes_cleared_pause() {
+        let base = Settings { pause: Some(PauseState::Indefinitely), ..Settings::default() };
+        let ours = Settings { pause: None, ..base.clone() };
+        let disk = base.clone();
+        let merged = ours.merge(&base, &disk).unwrap();
+        assert_eq!(merged.pause, None);
+        assert!(!to_map(&merged).unwrap().contains_key("pause"));
+    }
 }
@@ -1,35 +1,71 @@
 use image::ImageBuffer;
 use image::Rgba;
+use image::RgbaImage;
 use tao::event_loop::ControlFlow;
 use tao::event_loop::EventLoopProxy;
 use tray_icon::Icon;
 use tray_icon::TrayIcon;
 use tray_icon::TrayIconBuilder;
 
+use crate::breakdown::UsageBreakdown;
+use crate::config::ColorMode;
+use crate::config::IconStyle;
+use crate::config::PRIMARY_MODEL;
 use crate::config::SharedState;
 use crate::config::UsageData;
+use crate::events::StateEvent;
+use crate::events::SubscriptionId;
+use crate::forecast::Forecast;
+use crate::levels::UsageLevels;
+use crate::pace::PACE_TIERS;
+use crate::pace::Pace;
+use crate::scheduler::RefreshHandle;
 use crate::menu::{MenuBuilder, UserEvent, MenuAction};
 
-use std::thread;
+use chrono::Local;
+use parking_lot::Mutex;
+
+// 折线图标使用的采样数量
+const SPARKLINE_SAMPLES: usize = 24;
+// 没有数据时的图标颜色
+const DEFAULT_COLOR: (u8, u8, u8) = (128, 128, 128);
 
 pub struct TrayManager {
     tray_icon: Option<TrayIcon>,
     state: SharedState,
     event_loop_proxy: Option<EventLoopProxy<UserEvent>>,
     menu_actions: std::collections::HashMap<String, String>,
+    subscription: Option<SubscriptionId>,
+    refresher: RefreshHandle,
 }
 
 impl TrayManager {
-    pub fn new(state: SharedState) -> Self {
+    pub fn new(state: SharedState, refresher: RefreshHandle) -> Self {
         Self { 
             tray_icon: None, 
             state, 
             event_loop_proxy: None,
             menu_actions: std::collections::HashMap::new(),
+            subscription: None,
+            refresher,
         }
     }
 
     pub fn set_event_loop_proxy(&mut self, proxy: EventLoopProxy<UserEvent>) {
+        if let Some(id) = self.subscription.take() {
+            self.state.unsubscribe(id);
+        }
+
+        // 用量、设置或设置警告变化时重建图标和菜单
+        let listener_proxy = Mutex::new(proxy.clone());
+        self.subscription = Some(self.state.subscribe(move |event| {
+            if matches!(
+                event,
+                StateEvent::UsageUpdated(_) | StateEvent::SettingsChanged(_) | StateEvent::SettingsWarningChanged(_)
+            ) {
+                let _ = listener_proxy.lock().send_event(UserEvent::UpdateTrayIcon);
+            }
+        }));
         self.event_loop_proxy = Some(proxy);
     }
 
@@ -38,9 +74,14 @@ impl TrayManager {
         let icon = create_default_icon();
         let lang = self.state.get_language();
         let interval = self.state.get_refresh_interval();
-        let data = UsageData::default();
+        // 使用从历史记录恢复的数据（如果有）
+        let data = self.state.get_usage_data();
         
-        let (menu, actions) = MenuBuilder::new(lang, interval, data).build();
+        let (menu, actions) = MenuBuilder::new(lang, interval, data)
+            .with_language_preference(self.state.get_language_preference())
+            .with_settings_warning(self.state.get_settings_warning())
+            .with_account_display(self.state.get_account_display())
+            .build();
         self.menu_actions = actions;
         
         self.tray_icon = Some(TrayIconBuilder::new()
@@ -53,25 +94,15 @@ impl TrayManager {
     pub fn handle_event(&mut self, event: UserEvent, control_flow: &mut ControlFlow) {
         match event {
             UserEvent::MenuEvent(event) => {
-                if let Some(action_str) = self.menu_actions.get(&event.id.0) {
-                    if let Some(action) = MenuAction::from_string(action_str) {
-                        action.handle(&self.state, &self.event_loop_proxy, control_flow);
-                    }
+                if let Some(action_str) = self.menu_actions.get(&event.id.0)
+                    && let Some(action) = MenuAction::from_string(action_str)
+                {
+                    action.handle(&self.state, &self.event_loop_proxy, control_flow);
                 }
             }
             UserEvent::UpdateData => {
-                // 异步更新数据，不阻塞UI
-                let state = self.state.clone();
-                let proxy = self.event_loop_proxy.clone();
-                
-                thread::spawn(move || {
-                    if let Err(e) = state.update_usage_data() {
-                        eprintln!("Failed to update data: {}", e);
-                    }
-                    if let Some(proxy) = proxy {
-                        let _ = proxy.send_event(UserEvent::UpdateTrayIcon);
-                    }
-                });
+                // 交给刷新线程处理，更新完成后通过 UsageUpdated 事件刷新图标
+                self.refresher.request_refresh();
             }
             UserEvent::UpdateTrayIcon => {
                 self.update_tray_icon();
@@ -84,8 +115,56 @@ impl TrayManager {
         let lang = self.state.get_language();
         let interval = self.state.get_refresh_interval();
 
-        let icon = create_icon(&data);
-        let (menu, actions) = MenuBuilder::new(lang, interval, data).build();
+        let color_mode = self.state.get_color_mode();
+        let skip_weekends = self.state.get_skip_weekends();
+
+        let forecast = self.compute_forecast(&data);
+        let pace = Pace::compute(&data, Local::now(), skip_weekends);
+        let icon_pace = if color_mode == ColorMode::Pace { pace.as_ref() } else { None };
+
+        let breakdown = self.compute_breakdown();
+        let icon_style = self.state.get_icon_style();
+
+        let levels = self.state.get_usage_levels(&data);
+        let mut color = get_icon_color(&data, forecast.as_ref(), icon_pace, &levels);
+        let usage_level = levels.level_for(data.percentage).clone();
+        // 暂停轮询期间数据自然会过期，不再单独提示
+        let pause = self.state.get_pause();
+        let stale = self.state.get_expected_interval_secs().is_some_and(|secs| data.is_stale(secs, Local::now()));
+        if stale || pause.is_some() {
+            color = dim_color(color);
+        }
+        let mut image = match icon_style {
+            // 从未成功获取过数据时显示灰色
+            _ if !data.has_snapshot() => disc_image(DEFAULT_COLOR),
+            IconStyle::Disc => disc_image(color),
+            IconStyle::Sparkline => sparkline_image(&self.recent_usage(&data), color),
+            IconStyle::Bars => {
+                let daily: Vec<f32> = breakdown.iter().flat_map(|b| &b.days).map(|d| d.requests as f32).collect();
+                bars_image(&daily, color)
+            }
+        };
+        // 刷新失败时保留上次的颜色，并加上错误标记
+        if data.error.is_some() {
+            draw_error_badge(&mut image);
+        }
+        if pause.is_some() {
+            draw_pause_badge(&mut image);
+        }
+        let icon = create_icon(image);
+        let (menu, actions) = MenuBuilder::new(lang, interval, data)
+            .with_language_preference(self.state.get_language_preference())
+            .with_settings_warning(self.state.get_settings_warning())
+            .with_forecast(forecast)
+            .with_breakdown(breakdown)
+            .with_pace(color_mode, skip_weekends, pace)
+            .with_icon_style(icon_style)
+            .with_levels(self.state.get_level_preset(), self.state.has_custom_levels(), usage_level)
+            .with_account_display(self.state.get_account_display())
+            .with_stale(stale)
+            .with_quiet_schedule(self.state.get_quiet_schedule())
+            .with_pause(pause)
+            .build();
 
         self.menu_actions = actions;
         if let Some(tray_icon) = &mut self.tray_icon {
@@ -95,6 +174,51 @@ impl TrayManager {
             tray_icon.set_menu(Some(Box::new(menu)));
         }
     }
+
+    // 当前账户最近若干次成功采样的主模型用量
+    fn recent_usage(&self, data: &UsageData) -> Vec<f32> {
+        let Some(history) = self.state.history() else {
+            return Vec::new();
+        };
+        match history.latest_samples(SPARKLINE_SAMPLES) {
+            Ok(samples) => samples
+                .iter()
+                .filter(|s| s.error.is_none() && s.account == data.email)
+                .filter_map(|s| s.model(PRIMARY_MODEL))
+                .map(|m| m.used as f32)
+                .collect(),
+            Err(e) => {
+                eprintln!("Failed to load usage history: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    fn compute_breakdown(&self) -> Option<UsageBreakdown> {
+        let history = self.state.history()?;
+        let today = Local::now().date_naive();
+        let from = UsageBreakdown::first_day(today).and_hms_opt(0, 0, 0)?.and_local_timezone(Local).earliest()?;
+        match history.samples_since(from) {
+            Ok(samples) => Some(UsageBreakdown::compute(&samples, today)),
+            Err(e) => {
+                eprintln!("Failed to load usage history: {}", e);
+                None
+            }
+        }
+    }
+
+    fn compute_forecast(&self, data: &UsageData) -> Option<Forecast> {
+        let history = self.state.history()?;
+        let now = Local::now();
+        let samples = match history.samples_since(Forecast::window_start(data, now)) {
+            Ok(samples) => samples,
+            Err(e) => {
+                eprintln!("Failed to load usage history: {}", e);
+                Vec::new()
+            }
+        };
+        Forecast::compute(data, &samples, now)
+    }
 }
 
 fn create_default_icon() -> Icon {
@@ -120,12 +244,18 @@ fn create_default_icon() -> Icon {
         })
 }
 
-fn create_icon(usage_data: &UsageData) -> Icon {
-    let percentage = usage_data.percentage;
-    let (r, g, b) = get_color_for_usage(percentage);
+fn create_icon(image: RgbaImage) -> Icon {
+    let (width, height) = image.dimensions();
+    Icon::from_rgba(image.into_raw(), width, height)
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to create usage icon: {}, using default", e);
+            create_default_icon()
+        })
+}
 
+fn disc_image((r, g, b): (u8, u8, u8)) -> RgbaImage {
     let icon_size = 32;
-    let icon = ImageBuffer::<Rgba<u8>, Vec<u8>>::from_fn(icon_size, icon_size, |x, y| {
+    ImageBuffer::from_fn(icon_size, icon_size, |x, y| {
         let center_x = icon_size as f32 / 2.0;
         let center_y = icon_size as f32 / 2.0;
         let distance = ((x as f32 - center_x).powi(2) + (y as f32 - center_y).powi(2)).sqrt();
@@ -135,23 +265,123 @@ fn create_icon(usage_data: &UsageData) -> Icon {
         } else { 
             Rgba([0, 0, 0, 0]) 
         }
-    });
+    })
+}
 
-    Icon::from_rgba(icon.into_raw(), icon_size, icon_size)
-        .unwrap_or_else(|e| {
-            eprintln!("Failed to create usage icon: {}, using default", e);
-            create_default_icon()
-        })
+// 折线图：线条使用用量颜色，线下区域半透明填充
+fn sparkline_image(values: &[f32], (r, g, b): (u8, u8, u8)) -> RgbaImage {
+    if values.len() < 2 {
+        return disc_image((r, g, b));
+    }
+
+    let icon_size = 32u32;
+    let (min, max) = values.iter().fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    let range = (max - min).max(1.0);
+    let top = 4.0;
+    let bottom = (icon_size - 4) as f32;
+
+    // 每一列对应的折线高度
+    let line_y = |x: u32| {
+        let pos = x as f32 / (icon_size - 1) as f32 * (values.len() - 1) as f32;
+        let i = (pos.floor() as usize).min(values.len() - 2);
+        let value = values[i] + (values[i + 1] - values[i]) * (pos - i as f32);
+        bottom - (value - min) / range * (bottom - top)
+    };
+
+    ImageBuffer::from_fn(icon_size, icon_size, |x, y| {
+        let y_line = line_y(x);
+        let y = y as f32;
+        if (y - y_line).abs() <= 1.5 {
+            Rgba([r, g, b, 255])
+        } else if y > y_line && y <= bottom + 1.0 {
+            Rgba([r, g, b, 90])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    })
 }
 
-fn get_color_for_usage(percentage: f32) -> (u8, u8, u8) {
-    if percentage >= 90.0 {
-        (230, 40, 40) // 红色
-    } else if percentage >= 70.0 {
-        (250, 150, 30) // 橙色
-    } else if percentage >= 50.0 {
-        (250, 230, 30) // 黄色
-    } else {
-        (50, 200, 100) // 绿色
+// 柱状图：每个值一根柱子，底部保留一条基线
+fn bars_image(values: &[f32], (r, g, b): (u8, u8, u8)) -> RgbaImage {
+    if values.is_empty() {
+        return disc_image((r, g, b));
     }
+
+    let icon_size = 32u32;
+    let max = values.iter().cloned().fold(0.0, f32::max).max(1.0);
+    let slot = icon_size as f32 / values.len() as f32;
+    let baseline = icon_size - 3;
+
+    ImageBuffer::from_fn(icon_size, icon_size, |x, y| {
+        if y == baseline {
+            return Rgba([r, g, b, 120]);
+        }
+        let i = ((x as f32 / slot) as usize).min(values.len() - 1);
+        // 柱子之间留出间隔
+        let within = x as f32 - i as f32 * slot;
+        if within < 1.0 || y > baseline {
+            return Rgba([0, 0, 0, 0]);
+        }
+        let height = (values[i] / max * (baseline - 2) as f32).round() as u32;
+        if y + height >= baseline && values[i] > 0.0 {
+            Rgba([r, g, b, 255])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    })
+}
+
+// 在右下角绘制带白边的红色错误标记
+fn draw_error_badge(image: &mut RgbaImage) {
+    let (width, height) = image.dimensions();
+    let radius = width as f32 / 5.0;
+    let center_x = width as f32 - radius - 1.0;
+    let center_y = height as f32 - radius - 1.0;
+
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let distance = ((x as f32 - center_x).powi(2) + (y as f32 - center_y).powi(2)).sqrt();
+        if distance < radius - 1.5 {
+            *pixel = Rgba([230, 40, 40, 255]);
+        } else if distance < radius {
+            *pixel = Rgba([255, 255, 255, 255]);
+        }
+    }
+}
+
+// 在左下角绘制暂停标记：深色圆底上两条白色竖线
+fn draw_pause_badge(image: &mut RgbaImage) {
+    let (width, height) = image.dimensions();
+    let radius = width as f32 / 5.0;
+    let center_x = radius + 1.0;
+    let center_y = height as f32 - radius - 1.0;
+
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let (dx, dy) = (x as f32 - center_x, y as f32 - center_y);
+        if (dx * dx + dy * dy).sqrt() >= radius {
+            continue;
+        }
+        let in_bar = dy.abs() < radius * 0.55 && (0.6..=2.2).contains(&dx.abs());
+        *pixel = if in_bar { Rgba([255, 255, 255, 255]) } else { Rgba([60, 60, 60, 255]) };
+    }
+}
+
+fn get_icon_color(usage_data: &UsageData, forecast: Option<&Forecast>, pace: Option<&Pace>, levels: &UsageLevels) -> (u8, u8, u8) {
+    // 节奏模式下按超前程度分档着色，额度已用完时仍按使用率显示最高等级
+    let level = match pace {
+        Some(pace) if usage_data.percentage < 100.0 => levels.level_for_tier(pace.tier(), PACE_TIERS),
+        _ => levels.level_for(usage_data.percentage),
+    };
+
+    // 预计在重置前用完时，提前显示为警告等级的颜色
+    let warning = levels.warning();
+    if level.threshold < warning.threshold && forecast.is_some_and(Forecast::overruns) {
+        return warning.rgb();
+    }
+    level.rgb()
+}
+
+// 数据过期时将颜色向灰色靠拢
+fn dim_color((r, g, b): (u8, u8, u8)) -> (u8, u8, u8) {
+    let mix = |c: u8| ((c as u16 + 128 * 2) / 3) as u8;
+    (mix(r), mix(g), mix(b))
 }
@@ -0,0 +1,64 @@
+use std::net::IpAddr;
+use std::net::UdpSocket;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+// 墙上时间比单调时间多走超过该值时视为刚从睡眠中唤醒
+const RESUME_THRESHOLD: Duration = Duration::from_secs(30);
+
+// 仅用于查询路由表的地址，不会真正发送数据
+const ROUTE_PROBE_ADDR: &str = "1.1.1.1:443";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WakeReason {
+    // 系统从睡眠中恢复或墙上时间发生跳变
+    Resumed,
+    // 网络连接恢复或切换了网络
+    NetworkChanged,
+}
+
+// 通过定期调用 poll 检测睡眠唤醒和网络变化
+pub struct WakeDetector {
+    monotonic: Instant,
+    wall: SystemTime,
+    network: Option<IpAddr>,
+}
+
+impl WakeDetector {
+    pub fn new() -> Self {
+        Self { monotonic: Instant::now(), wall: SystemTime::now(), network: route_local_addr() }
+    }
+
+    pub fn poll(&mut self) -> Option<WakeReason> {
+        let (monotonic, wall) = (Instant::now(), SystemTime::now());
+        let monotonic_elapsed = monotonic.duration_since(self.monotonic);
+        // 墙上时间倒退时同样视为跳变
+        let wall_elapsed = wall.duration_since(self.wall).unwrap_or(Duration::MAX);
+        self.monotonic = monotonic;
+        self.wall = wall;
+
+        // 单调时钟在睡眠期间不前进，两者的差值即为睡眠时长
+        let resumed = wall_elapsed.abs_diff(monotonic_elapsed) > RESUME_THRESHOLD;
+
+        // 断网时不刷新，等连接恢复后再刷新
+        let network = route_local_addr();
+        let network_changed = network.is_some() && network != self.network;
+        self.network = network;
+
+        if resumed {
+            Some(WakeReason::Resumed)
+        } else if network_changed {
+            Some(WakeReason::NetworkChanged)
+        } else {
+            None
+        }
+    }
+}
+
+// 默认路由使用的本地地址，无网络时返回 None
+fn route_local_addr() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect(ROUTE_PROBE_ADDR).ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}